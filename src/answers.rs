@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::input::input_dir;
+use crate::parse::lines;
+use crate::{ParseError, Part};
+
+pub fn answers_path() -> PathBuf {
+    input_dir().join("answers.txt")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Example, InputKind::Real];
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputKind::Example => write!(f, "example"),
+            InputKind::Real => write!(f, "real"),
+        }
+    }
+}
+
+// Known-good answers, one per line as `<day> <part> <example|real> <answer>`. Blank lines and
+// lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Registry {
+    answers: BTreeMap<(u32, u32, InputKind), String>,
+}
+
+impl Registry {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        for line in lines(s) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let tokens: Vec<&str> = text.split_whitespace().collect();
+            let [day, part, kind, answer] = tokens[..] else {
+                return Err(line.error(text, "expected `<day> <part> <example|real> <answer>`"));
+            };
+            let day = line.parse::<u32>(day)?;
+            let part = line
+                .parse::<u32>(part)
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| line.error(part, "expected part 1 or 2"))?;
+            let kind = match kind {
+                "example" => InputKind::Example,
+                "real" => InputKind::Real,
+                _ => return Err(line.error(kind, "expected `example` or `real`")),
+            };
+            if answers
+                .insert((day, part.number(), kind), answer.to_string())
+                .is_some()
+            {
+                return Err(line.error(text, "answer is listed more than once"));
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u32, part: Part, kind: InputKind) -> Option<&str> {
+        self.answers
+            .get(&(day, part.number(), kind))
+            .map(|a| a.as_str())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    // The computed answer disagrees with the registry, or the solution failed to produce one.
+    Fail {
+        expected: Option<String>,
+        actual: Result<String, String>,
+    },
+    Missing {
+        actual: String,
+    },
+}
+
+pub fn check(expected: Option<&str>, actual: Result<String, String>) -> Verdict {
+    match (expected, actual) {
+        (Some(e), Ok(a)) if e == a => Verdict::Pass,
+        (None, Ok(a)) => Verdict::Missing { actual: a },
+        (e, actual) => Verdict::Fail {
+            expected: e.map(|e| e.to_string()),
+            actual,
+        },
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::answers::InputKind;
+use crate::input::Source;
+use crate::runner::{Day, RunError, millis, solve_str};
+use crate::solution::Part;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(p) => write!(f, "part{}", p.number()),
+        }
+    }
+}
+
+// Summary of repeated timings of one phase. The median is the lower middle sample and p95 uses
+// the nearest-rank method, so both are always one of the measured times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        Some(Stats {
+            runs: n,
+            min: sorted[0],
+            median: sorted[(n - 1) / 2],
+            p95: sorted[(95 * n).div_ceil(100) - 1],
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+// Solves `source` `runs` times and summarises each phase. The input is only read once, so the
+// parse timings don't include any I/O.
+pub fn bench(
+    day: &Day,
+    source: &Source,
+    kind: InputKind,
+    part: Option<Part>,
+    runs: usize,
+) -> Result<Vec<Record>, RunError> {
+    let input = source.read()?;
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+    for _ in 0..runs {
+        let timings = solve_str(day, &input, source, kind, part)?.timings;
+        parse.push(timings.parse);
+        for p in Part::selected(part) {
+            if let Some(time) = timings.get(p) {
+                parts[p.number() as usize - 1].push(time);
+            }
+        }
+    }
+
+    let mut records = Vec::new();
+    let phases = [
+        (Phase::Parse, &parse),
+        (Phase::Part(Part::One), &parts[0]),
+        (Phase::Part(Part::Two), &parts[1]),
+    ];
+    for (phase, samples) in phases {
+        if let Some(stats) = Stats::from_samples(samples) {
+            records.push(Record {
+                day: day.day,
+                phase,
+                stats,
+            });
+        }
+    }
+    Ok(records)
+}
+
+pub fn print_text(records: &[Record]) {
+    for r in records {
+        println!(
+            "Day {:02} {}: min {:.3} ms, median {:.3} ms, p95 {:.3} ms ({} runs)",
+            r.day,
+            r.phase,
+            millis(r.stats.min),
+            millis(r.stats.median),
+            millis(r.stats.p95),
+            r.stats.runs
+        );
+    }
+}
+
+// One JSON object per phase, so results can be diffed or fed to a plotting script.
+pub fn to_json(records: &[Record]) -> String {
+    let objects: Vec<String> = records
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"day\": {}, \"phase\": \"{}\", \"runs\": {}, \"min_ms\": {:.6}, \"median_ms\": {:.6}, \"p95_ms\": {:.6}}}",
+                r.day,
+                r.phase,
+                r.stats.runs,
+                millis(r.stats.min),
+                millis(r.stats.median),
+                millis(r.stats.p95)
+            )
+        })
+        .collect();
+    if objects.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", objects.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(v: &[u64]) -> Vec<Duration> {
+        v.iter().map(|&x| Duration::from_millis(x)).collect()
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = ms(&[5, 1, 4, 2, 3, 100, 6, 7, 8, 9]);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 10);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.p95, Duration::from_millis(100));
+
+        let single = Stats::from_samples(&ms(&[3])).unwrap();
+        let three = Duration::from_millis(3);
+        assert_eq!(
+            (single.min, single.median, single.p95),
+            (three, three, three)
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn json_summary() {
+        let stats = Stats::from_samples(&ms(&[1, 2])).unwrap();
+        let records = [Record {
+            day: 8,
+            phase: Phase::Part(Part::Two),
+            stats,
+        }];
+        assert_eq!(
+            to_json(&records),
+            "[\n  {\"day\": 8, \"phase\": \"part2\", \"runs\": 2, \"min_ms\": 1.000000, \"median_ms\": 1.000000, \"p95_ms\": 2.000000}\n]"
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+}
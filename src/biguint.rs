@@ -0,0 +1,170 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul};
+
+const BASE: u64 = 1_000_000_000;
+
+// Arbitrary-size unsigned integer, for counts that can outgrow any primitive. Stored as base-10⁹
+// limbs, least significant first, with no trailing zero limbs (so zero is empty).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self { limbs: vec![] }
+    }
+
+    pub fn one() -> Self {
+        Self::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        let mut output: u64 = 0;
+        for &limb in self.limbs.iter().rev() {
+            output = output.checked_mul(BASE)?.checked_add(limb as u64)?;
+        }
+        Some(output)
+    }
+
+    fn trim(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(mut n: u64) -> Self {
+        let mut limbs = vec![];
+        while n > 0 {
+            limbs.push((n % BASE) as u32);
+            n /= BASE;
+        }
+        Self { limbs }
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        let mut carry = 0;
+        for i in 0..self.limbs.len() {
+            let sum = self.limbs[i] as u64 + rhs.limbs.get(i).copied().unwrap_or(0) as u64 + carry;
+            self.limbs[i] = (sum % BASE) as u32;
+            carry = sum / BASE;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let mut output = self.clone();
+        output += rhs;
+        output
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+        let mut wide = vec![0u64; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let cur = wide[i + j] + a as u64 * b as u64 + carry;
+                wide[i + j] = cur % BASE;
+                carry = cur / BASE;
+            }
+            let mut k = i + rhs.limbs.len();
+            while carry > 0 {
+                let cur = wide[k] + carry;
+                wide[k] = cur % BASE;
+                carry = cur / BASE;
+                k += 1;
+            }
+        }
+        BigUint {
+            limbs: wide.into_iter().map(|l| l as u32).collect(),
+        }
+        .trim()
+    }
+}
+
+impl<'a> Sum<&'a BigUint> for BigUint {
+    fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> Self {
+        let mut output = BigUint::zero();
+        for x in iter {
+            output += x;
+        }
+        output
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((last, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+        write!(f, "{}", last)?;
+        for limb in rest.iter().rev() {
+            write!(f, "{:09}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = BigUint::from(u64::MAX);
+        let b = &a + &BigUint::one();
+        assert_eq!(b.to_string(), "18446744073709551616");
+        assert_eq!(b.to_u64(), None);
+        assert_eq!(
+            (&a * &a).to_string(),
+            "340282366920938463426481119284349108225"
+        );
+        assert_eq!((&a * &BigUint::zero()), BigUint::zero());
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_000).to_string(), "1000000000");
+        assert_eq!(BigUint::from(42).to_u64(), Some(42));
+        assert!(a < b && BigUint::zero() < BigUint::one());
+        assert_eq!([a.clone(), a.clone()].iter().sum::<BigUint>(), &a + &a);
+    }
+}
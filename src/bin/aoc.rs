@@ -0,0 +1,207 @@
+use std::process::ExitCode;
+
+use aoc2025::Part;
+use aoc2025::answers::{InputKind, Registry, answers_path};
+use aoc2025::bench::{self, bench};
+use aoc2025::days;
+use aoc2025::input::Source;
+use aoc2025::runner::{Day, input_path, run, verify};
+
+const USAGE: &str =
+    "usage: aoc run <day|all> [--part <1|2>] [--input <path> [--kind <example|real>]]
+       aoc verify [<day|all>] [--part <1|2>] [--answers <path>]
+       aoc bench <day|all> [--part <1|2>] [--runs <n>] [--input <path> [--kind <example|real>]]
+                 [--format <text|json>]
+
+A <path> of `-` reads stdin, solved as a real input unless --kind says it's an example.
+Inputs and answers default to $AOC_INPUT_DIR, else `inputs`.";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Run,
+    Verify,
+    Bench,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Args {
+    command: Command,
+    days: Vec<Day>,
+    part: Option<Part>,
+    input: Option<Source>,
+    kind: Option<InputKind>,
+    answers: Source,
+    runs: usize,
+    format: Format,
+}
+
+fn parse_days(arg: &str) -> Result<Vec<Day>, String> {
+    if arg == "all" {
+        return Ok(days::ALL.to_vec());
+    }
+    let n = arg
+        .parse::<u32>()
+        .map_err(|_| format!("invalid day `{}`", arg))?;
+    Ok(vec![
+        days::get(n).ok_or_else(|| format!("no solution for day {}", n))?,
+    ])
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut iter = args.iter().peekable();
+    let command = match iter.next().map(|s| s.as_str()) {
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        Some("bench") => Command::Bench,
+        Some(cmd) => return Err(format!("unknown command `{}`", cmd)),
+        None => return Err("missing command".to_string()),
+    };
+
+    let days = match iter.peek() {
+        Some(d) if !d.starts_with("--") => parse_days(iter.next().unwrap())?,
+        _ if command == Command::Verify => days::ALL.to_vec(),
+        _ => return Err("missing day".to_string()),
+    };
+
+    let mut part = None;
+    let mut input = None;
+    let mut kind = None;
+    let mut answers = Source::File(answers_path());
+    let mut runs = 10;
+    let mut format = Format::Text;
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("missing value for `{}`", flag))?;
+        match (command, flag.as_str()) {
+            (_, "--part") => {
+                part = value
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .map(Some)
+                    .ok_or_else(|| format!("invalid part `{}`", value))?;
+            }
+            (Command::Run | Command::Bench, "--input") => input = Some(Source::from_arg(value)),
+            (Command::Run | Command::Bench, "--kind") => {
+                kind = match value.as_str() {
+                    "example" => Some(InputKind::Example),
+                    "real" => Some(InputKind::Real),
+                    _ => return Err(format!("invalid input kind `{}`", value)),
+                };
+            }
+            (Command::Verify, "--answers") => answers = Source::from_arg(value),
+            (Command::Bench, "--runs") => {
+                runs = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("invalid run count `{}`", value))?;
+            }
+            (Command::Bench, "--format") => {
+                format = match value.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("invalid format `{}`", value)),
+                };
+            }
+            _ => return Err(format!("unknown flag `{}`", flag)),
+        }
+    }
+
+    if input.is_some() && days.len() > 1 {
+        return Err("--input needs a single day".to_string());
+    }
+    if kind.is_some() && input.is_none() {
+        return Err("--kind needs --input".to_string());
+    }
+
+    Ok(Args {
+        command,
+        days,
+        part,
+        input,
+        kind,
+        answers,
+        runs,
+        format,
+    })
+}
+
+fn run_days(args: &Args) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+    for day in &args.days {
+        if args.days.len() > 1 {
+            println!("Day {:02}", day.day);
+        }
+        let kind = args.kind.unwrap_or(InputKind::Real);
+        if let Err(e) = run(day, args.part, args.input.as_ref(), kind) {
+            eprintln!("error: {}", e);
+            code = ExitCode::FAILURE;
+        }
+    }
+    code
+}
+
+fn verify_days(args: &Args) -> ExitCode {
+    let registry = match args.answers.read() {
+        Ok(s) => Registry::parse(&s).map_err(|e| e.with_file(args.answers.path()).to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    match registry {
+        Ok(registry) if verify(&args.days, args.part, &registry) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Benchmarks the real inputs, printing everything at the end so JSON output stays one document.
+fn bench_days(args: &Args) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+    let mut records = Vec::new();
+    for day in &args.days {
+        let source = args
+            .input
+            .clone()
+            .unwrap_or_else(|| Source::File(input_path(day.day)));
+        let kind = args.kind.unwrap_or(InputKind::Real);
+        match bench(day, &source, kind, args.part, args.runs) {
+            Ok(r) => records.extend(r),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    match args.format {
+        Format::Text => bench::print_text(&records),
+        Format::Json => println!("{}", bench::to_json(&records)),
+    }
+    code
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match args.command {
+        Command::Run => run_days(&args),
+        Command::Verify => verify_days(&args),
+        Command::Bench => bench_days(&args),
+    }
+}
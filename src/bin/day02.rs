@@ -1,172 +1,8 @@
-use std::collections::HashSet;
-use std::io::Read;
-use std::time::Instant;
+use std::process::ExitCode;
 
-fn parse(filename: &str) -> Vec<[usize; 2]> {
-    let mut file = std::fs::File::open(filename).expect("failed to open file");
-    let mut s = String::new();
-    file.read_to_string(&mut s)
-        .expect("failed to read file to string");
-    s.trim()
-        .split(',')
-        .map(|range| {
-            range
-                .split('-')
-                .map(|n| n.parse::<usize>().expect("failed to parse usize"))
-                .collect::<Vec<usize>>()
-                .try_into()
-                .unwrap()
-        })
-        .collect()
-}
-
-fn part1(id_ranges: &[[usize; 2]]) -> usize {
-    let mut output = 0;
-    for range in id_ranges {
-        let mut i = range[0];
-        let end = range[1];
-        while i <= end {
-            let ndigits = i.ilog10() as usize + 1;
-            if ndigits % 2 == 0 {
-                // Even number of digits
-                let n2 = ndigits / 2;
-                let k = 10_usize.pow(n2 as u32);
-                let left = i / k;
-                let right = i % k;
-                if left == right {
-                    output += i;
-                    i = (left + 1) * k + left + 1;
-                } else if left > right {
-                    i = left * k + left;
-                } else {
-                    i = (left + 1) * k + left + 1;
-                }
-            } else {
-                // Skip intervals that don't even have even number of digits
-                i = 10_usize.pow(ndigits as u32);
-            }
-        }
-    }
-    output
-}
-
-fn check_valid(_id: usize, length: usize) -> bool {
-    let id = _id.to_string();
-    let nrepeats = id.len() / length;
-    if nrepeats < 2 {
-        return true;
-    }
-    let pattern = &id[..length];
-    let mut valid = false;
-    for idx in 1..nrepeats {
-        if pattern != &id[length * idx..length * (idx + 1)] {
-            valid = true;
-            break;
-        }
-    }
-    valid
-}
-
-// parallel counters. if they can generate a number in range, then invalid
-fn generator(length: usize, nrepeat: usize, range: [usize; 2]) -> Vec<usize> {
-    let k = 10_usize.pow(length as u32);
-    let mut generator = 1;
-    for _ in 1..nrepeat {
-        generator *= k;
-        generator += 1;
-    }
-
-    let mut output = vec![];
-    let mut i = range[0];
-    while i <= range[1] {
-        if i % generator == 0 {
-            output.push(i);
-            i += generator;
-        } else {
-            i = (i / generator + 1) * generator;
-        }
-    }
-    output
-}
-
-fn part2a(id_ranges: &[[usize; 2]]) -> usize {
-    let mut invalids: HashSet<usize> = HashSet::new();
-    for range in id_ranges {
-        let end = range[1];
-        let n_end_digits = end.ilog10() as usize + 1;
-        let maxlength = n_end_digits / 2 + 1;
-        for length in 1..maxlength {
-            let nrepeat = n_end_digits / length;
-            let new_invalids = generator(length, nrepeat, *range);
-            let new_invalids: HashSet<usize> = new_invalids.into_iter().collect();
-            invalids = invalids
-                .union(&new_invalids)
-                .copied()
-                .collect::<HashSet<usize>>();
-        }
-    }
-    invalids.into_iter().sum::<usize>()
-}
-
-fn part2(id_ranges: &[[usize; 2]]) -> usize {
-    let mut invalids = HashSet::new();
-    for range in id_ranges {
-        //println!("Interval: {}-{}", range[0], range[1]);
-        let end = range[1];
-        let n_end_digits = end.ilog10() as usize + 1;
-        let maxlength = n_end_digits / 2 + 1;
-        for length in 1..maxlength {
-            let mut i = range[0];
-            while i <= end {
-                let ndigits = i.ilog10() as usize + 1;
-                if ndigits % length != 0 {
-                    // Skip interval to next valid length
-                    i = 10_usize.pow(ndigits as u32);
-                }
-                let k = 10_usize.pow(length as u32);
-                let kk = 10_usize.pow((ndigits - length) as u32);
-                let left = i / kk;
-                let right = i % k;
-                if right < left {
-                    //println!("right < left ||| i: {}, right: {}, left: {}, length: {}, k: {}, kk: {}", i, right, left, length, k, kk);
-                    i = (i / k) * k + left;
-                } else if right > left {
-                    //println!("right > left ||| i: {}, right: {}, left: {}, length: {}, k: {}, kk: {}", i, right, left, length, k, kk);
-                    i += k;
-                    i = (i / k) * k + left;
-                    //i = (i / k) * k;
-                } else {
-                    // left == right => might be invalid
-                    if !check_valid(i, length) {
-                        //println!("invalid: {}", i);
-                        invalids.insert(i);
-                    } else {
-                        //println!("valid  : {}", i);
-                    }
-                    i += 1;
-                }
-            }
-        }
-    }
-    invalids.into_iter().sum::<usize>()
-}
-
-fn main() {
-    let t0 = Instant::now();
-
-    let id_ranges = parse("inputs/day02a.txt");
-    let answer1 = part1(&id_ranges);
-    let answer2 = part2(&id_ranges);
-    println!("Examples:");
-    println!("Part 1: {}", answer1);
-    println!("Part 2: {}", answer2);
-
-    let id_ranges = parse("inputs/day02.txt");
-    let answer1 = part1(&id_ranges);
-    let answer2 = part2a(&id_ranges);
-    println!("Challenges:");
-    println!("Part 1: {}", answer1);
-    println!("Part 2: {}", answer2);
+use aoc2025::days::day02::Day02;
+use aoc2025::runner::{self, Day};
 
-    println!("Time: {} ms", 1000.0 * t0.elapsed().as_secs_f64());
+fn main() -> ExitCode {
+    runner::main(&Day::new::<Day02>())
 }
@@ -0,0 +1,117 @@
+use crate::grid::Grid;
+use crate::point::Point2;
+
+// Coordinate compression along one axis. Every distinct value gets an index of its own, and so
+// does the (possibly empty) run of values between each neighbouring pair, so index `2i` is
+// `values[i]` exactly and `2i + 1` is the gap after it. Each index stands for a range of real
+// values, which is what lets a compressed cell be expanded back to its real size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compression {
+    values: Vec<i64>,
+}
+
+impl Compression {
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort();
+        values.dedup();
+        Self { values }
+    }
+
+    // Number of compressed indices.
+    pub fn len(&self) -> usize {
+        (2 * self.values.len()).saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // The index covering `v`, None if it's outside the compressed range.
+    pub fn index(&self, v: i64) -> Option<usize> {
+        match self.values.binary_search(&v) {
+            Ok(i) => Some(2 * i),
+            Err(i) if 0 < i && i < self.values.len() => Some(2 * i - 1),
+            Err(_) => None,
+        }
+    }
+
+    // The inclusive range of real values index `i` stands for. Empty (`lo > hi`) for a gap
+    // between neighbouring values.
+    pub fn span(&self, i: usize) -> (i64, i64) {
+        if i.is_multiple_of(2) {
+            (self.values[i / 2], self.values[i / 2])
+        } else {
+            (self.values[i / 2] + 1, self.values[i / 2 + 1] - 1)
+        }
+    }
+
+    // How many real values index `i` stands for.
+    pub fn width(&self, i: usize) -> i64 {
+        let (lo, hi) = self.span(i);
+        hi - lo + 1
+    }
+}
+
+// A grid over compressed x and y coordinates, where each cell stands for a whole rectangle of
+// real points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressedGrid<T> {
+    pub xs: Compression,
+    pub ys: Compression,
+    pub grid: Grid<T>,
+}
+
+impl<T: Clone> CompressedGrid<T> {
+    pub fn new(xs: Compression, ys: Compression, fill: T) -> Self {
+        let grid = Grid::new(xs.len(), ys.len(), fill);
+        Self { xs, ys, grid }
+    }
+
+    // The cell covering `p`, None if it's outside the grid.
+    pub fn cell(&self, p: Point2) -> Option<(usize, usize)> {
+        Some((self.xs.index(p.x)?, self.ys.index(p.y)?))
+    }
+
+    // Number of real points in cell `(x, y)`.
+    pub fn area(&self, (x, y): (usize, usize)) -> i64 {
+        self.xs.width(x) * self.ys.width(y)
+    }
+
+    // The cells weighted by how many real points each stands for, so prefix sums over the result
+    // count real points.
+    pub fn weighted(&self, mut weight: impl FnMut(&T) -> i64) -> Grid<i64> {
+        let mut output = Grid::new(self.grid.width(), self.grid.height(), 0);
+        for (p, cell) in self.grid.iter() {
+            output[p] = weight(cell) * self.area(p);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compression() {
+        let xs = Compression::new([10, 3, 4, 10, 100]);
+        assert_eq!(xs.len(), 7);
+        assert_eq!(xs.index(3), Some(0));
+        assert_eq!(xs.index(7), Some(3));
+        assert_eq!(xs.index(100), Some(6));
+        assert_eq!(xs.index(101), None);
+        assert_eq!(xs.span(3), (5, 9));
+        assert_eq!(xs.width(1), 0);
+        assert_eq!((0..xs.len()).map(|i| xs.width(i)).sum::<i64>(), 98);
+    }
+
+    #[test]
+    fn real_areas() {
+        let mut g = CompressedGrid::new(Compression::new([0, 10]), Compression::new([0, 4]), 0);
+        assert_eq!(g.cell(Point2::new(5, 4)), Some((1, 2)));
+        g.grid[(1, 1)] = 1;
+        let counts = g.weighted(|&c| c).prefix_sums();
+        assert_eq!(counts.sum((0, 0), (2, 2)), 9 * 3);
+    }
+}
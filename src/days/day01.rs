@@ -0,0 +1,101 @@
+use std::cmp::{max, min};
+use std::fmt::Display;
+
+use crate::parse::lines;
+use crate::{NoAnswer, ParseError, Solution};
+
+fn parse(s: &str) -> Result<Vec<i64>, ParseError> {
+    lines(s)
+        .map(|line| {
+            let (dir, n) = line
+                .text
+                .split_at_checked(1)
+                .ok_or_else(|| line.error(line.text, "expected a rotation like `L68`"))?;
+            let out = line.parse::<i64>(n)?;
+            match dir {
+                "L" => Ok(-out),
+                "R" => Ok(out),
+                _ => Err(line.error(dir, "expected `L` or `R`")),
+            }
+        })
+        .collect()
+}
+
+fn spin(_start: i64, new: i64) -> i64 {
+    let tmp = _start + new;
+    let mut output = max(_start, tmp) / 100 - min(_start, tmp) / 100;
+    if new < 0 {
+        if tmp % 100 == 0 {
+            output += 1;
+        }
+        if _start % 100 == 0 {
+            output -= 1;
+        }
+    }
+    output
+}
+
+fn part1(sequence: &[i64]) -> i64 {
+    let mut last = 10000000000000050;
+    let mut cntr = 0;
+    for n in sequence {
+        last += n;
+        if last % 100 == 0 {
+            cntr += 1;
+        }
+    }
+    cntr
+}
+
+fn part2(sequence: &[i64]) -> i64 {
+    let mut last = 100000000000050;
+    let mut output = 0;
+    for n in sequence {
+        output += spin(last, *n);
+        last += *n;
+    }
+    output
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(sequence: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(sequence))
+    }
+
+    fn part2(sequence: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(sequence))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day01a.txt");
+
+    #[test]
+    fn parse_example() {
+        let sequence = parse(EXAMPLE).unwrap();
+        assert_eq!(sequence.len(), 10);
+        assert_eq!(sequence[..3], [-68, -30, 48]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 6);
+    }
+}
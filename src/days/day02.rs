@@ -0,0 +1,148 @@
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::parse::lines;
+use crate::{NoAnswer, ParseError, Solution};
+
+fn parse(s: &str) -> Result<Vec<[usize; 2]>, ParseError> {
+    let mut output = vec![];
+    for line in lines(s) {
+        for range in line
+            .text
+            .split(',')
+            .map(|r| r.trim())
+            .filter(|r| !r.is_empty())
+        {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or_else(|| line.error(range, "expected an ID range like `11-22`"))?;
+            let start = line.parse::<usize>(start)?;
+            let end = line.parse::<usize>(end)?;
+            if start == 0 || start > end {
+                return Err(line.error(range, "expected IDs from 1 upwards, in order"));
+            }
+            output.push([start, end]);
+        }
+    }
+    Ok(output)
+}
+
+fn part1(id_ranges: &[[usize; 2]]) -> usize {
+    let mut output = 0;
+    for range in id_ranges {
+        let mut i = range[0];
+        let end = range[1];
+        while i <= end {
+            let ndigits = i.ilog10() as usize + 1;
+            if ndigits.is_multiple_of(2) {
+                // Even number of digits
+                let n2 = ndigits / 2;
+                let k = 10_usize.pow(n2 as u32);
+                let left = i / k;
+                let right = i % k;
+                if left == right {
+                    output += i;
+                    i = (left + 1) * k + left + 1;
+                } else if left > right {
+                    i = left * k + left;
+                } else {
+                    i = (left + 1) * k + left + 1;
+                }
+            } else {
+                // Skip intervals that don't even have even number of digits
+                i = 10_usize.pow(ndigits as u32);
+            }
+        }
+    }
+    output
+}
+
+// parallel counters. if they can generate a number in range, then invalid
+fn generator(length: usize, nrepeat: usize, range: [usize; 2]) -> Vec<usize> {
+    let k = 10_usize.pow(length as u32);
+    let mut generator = 1;
+    for _ in 1..nrepeat {
+        generator *= k;
+        generator += 1;
+    }
+
+    let mut output = vec![];
+    let mut i = range[0];
+    while i <= range[1] {
+        if i.is_multiple_of(generator) {
+            output.push(i);
+            i += generator;
+        } else {
+            i = (i / generator + 1) * generator;
+        }
+    }
+    output
+}
+
+fn part2a(id_ranges: &[[usize; 2]]) -> usize {
+    let mut invalids: HashSet<usize> = HashSet::new();
+    for range in id_ranges {
+        let n_start_digits = range[0].ilog10() as usize + 1;
+        let n_end_digits = range[1].ilog10() as usize + 1;
+        // Each digit count repeats with its own periods, so split the range up by digit count
+        for ndigits in n_start_digits..=n_end_digits {
+            let lo = max(range[0], 10_usize.pow(ndigits as u32 - 1));
+            let hi = min(
+                range[1],
+                10_usize
+                    .checked_pow(ndigits as u32)
+                    .map_or(usize::MAX, |k| k - 1),
+            );
+            for length in 1..=ndigits / 2 {
+                if ndigits.is_multiple_of(length) {
+                    invalids.extend(generator(length, ndigits / length, [lo, hi]));
+                }
+            }
+        }
+    }
+    invalids.into_iter().sum::<usize>()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<[usize; 2]>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(id_ranges: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(id_ranges))
+    }
+
+    fn part2(id_ranges: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2a(id_ranges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day02a.txt");
+
+    #[test]
+    fn parse_example() {
+        let id_ranges = parse(EXAMPLE).unwrap();
+        assert_eq!(id_ranges.len(), 11);
+        assert_eq!(id_ranges[0], [11, 22]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 1227775554);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2a(&parse(EXAMPLE).unwrap()), 4174379265);
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::Display;
+
+use crate::parse::lines;
+use crate::{NoAnswer, ParseError, Solution};
+
+// Part 2 turns on twelve batteries in every bank, so each row needs at least that many.
+const BATTERIES_ON: usize = 12;
+
+fn parse(s: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    lines(s)
+        .map(|line| {
+            let row = line
+                .text
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as u64)
+                        .ok_or_else(|| line.char_error(i, "expected a battery joltage digit"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if row.len() < BATTERIES_ON {
+                let message = format!("expected at least {} batteries", BATTERIES_ON);
+                return Err(line.error(line.text, message));
+            }
+            Ok(row)
+        })
+        .collect()
+}
+
+fn part1(rows: &[Vec<u64>]) -> u64 {
+    let mut output = 0;
+    for row in rows {
+        // Find first digit
+        let mut max_digit = row[0];
+        let mut max_idx = 0;
+        for (i, n) in row[..row.len() - 1].iter().enumerate() {
+            if *n > max_digit {
+                max_digit = *n;
+                max_idx = i;
+            }
+        }
+
+        // Find second digit
+        let mut max_digit2 = 0;
+        for n in row[max_idx + 1..].iter() {
+            if *n > max_digit2 {
+                max_digit2 = *n;
+            }
+        }
+
+        // Joltage
+        let joltage = 10 * max_digit + max_digit2;
+        output += joltage;
+    }
+    output
+}
+
+fn first_max_digit(digits: &[u64], depth: usize) -> (u64, &[u64]) {
+    let mut max_digit = digits[0];
+    let mut max_idx = 0;
+    for (i, n) in digits[..digits.len() - (depth - 1)].iter().enumerate() {
+        if *n > max_digit {
+            max_digit = *n;
+            max_idx = i;
+        }
+    }
+    (max_digit, &digits[max_idx + 1..])
+}
+
+fn part2(rows: &[Vec<u64>]) -> u64 {
+    let mut output = 0;
+    for row in rows {
+        let mut digits = [0; BATTERIES_ON];
+        let mut last_slice: &[u64] = row;
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let depth = BATTERIES_ON - i;
+            let (new_digit, new_slice) = first_max_digit(last_slice, depth);
+            *digit = new_digit;
+            last_slice = new_slice;
+        }
+
+        // Joltage
+        let mut joltage = 0;
+        for (i, digit) in digits.iter().enumerate() {
+            joltage += 10_u64.pow((BATTERIES_ON - 1 - i) as u32) * digit;
+        }
+        output += joltage;
+    }
+    output
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rows: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(rows))
+    }
+
+    fn part2(rows: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day03a.txt");
+
+    #[test]
+    fn parse_example() {
+        let rows = parse(EXAMPLE).unwrap();
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| row.len() == 15));
+    }
+
+    #[test]
+    fn short_bank() {
+        let err = parse("987654321111111\n12\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (2, "12"));
+        assert_eq!(err.message, "expected at least 12 batteries");
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 357);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 3121910778619);
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use crate::grid::Grid;
+use crate::{NoAnswer, ParseError, Solution};
+
+fn parse(s: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(s, |c| match c {
+        '.' => Ok(0),
+        '@' => Ok(1),
+        _ => Err("expected `.` or `@`"),
+    })
+}
+
+// Rolls with fewer than four rolls around them.
+fn accessible(map: &Grid<u8>) -> Vec<(usize, usize)> {
+    let mut output = vec![];
+    for ((x, y), p) in map.iter() {
+        if *p == 1 {
+            let cntr = map.neighbors8(x, y).filter(|&n| map[n] == 1).count();
+            if cntr < 4 {
+                output.push((x, y));
+            }
+        }
+    }
+    output
+}
+
+fn part1(map: &Grid<u8>) -> u64 {
+    accessible(map).len() as u64
+}
+
+fn part2(map: &Grid<u8>) -> u64 {
+    let mut map = map.clone();
+    let mut output = 0;
+
+    loop {
+        let remove = accessible(&map);
+        if remove.is_empty() {
+            break;
+        }
+        for p in remove {
+            map[p] = 0;
+            output += 1;
+        }
+    }
+    output
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(map))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day04a.txt");
+
+    #[test]
+    fn parse_example() {
+        let map = parse(EXAMPLE).unwrap();
+        assert_eq!((map.width(), map.height()), (10, 10));
+        assert_eq!(map.row(0), [0, 0, 1, 1, 0, 1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 43);
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use crate::parse::lines;
+use crate::{NoAnswer, ParseError, Range, RangeSet, Solution};
+
+type Input = (Vec<(usize, usize)>, Vec<usize>);
+
+fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut part1 = true;
+    let mut fresh_ranges = vec![];
+    let mut available = vec![];
+    for line in lines(s) {
+        //println!("line: `{}`", line);
+        if line.text.is_empty() {
+            // Part 2 now
+            part1 = false;
+            continue;
+        }
+        if part1 {
+            let (start, end) = line
+                .text
+                .split_once('-')
+                .ok_or_else(|| line.error(line.text, "expected a fresh ID range like `3-5`"))?;
+            fresh_ranges.push((line.parse::<usize>(start)?, line.parse::<usize>(end)?));
+        } else {
+            available.push(line.parse::<usize>(line.text)?);
+        }
+    }
+    Ok((fresh_ranges, available))
+}
+
+fn fresh_set(fresh_ranges: &[(usize, usize)]) -> RangeSet {
+    fresh_ranges
+        .iter()
+        .map(|fr| Range::new(fr.0, fr.1))
+        .collect()
+}
+
+fn part1(tup: &Input) -> usize {
+    let (fresh_ranges, available) = tup;
+    let fresh = fresh_set(fresh_ranges);
+    available.iter().filter(|a| fresh.contains(**a)).count()
+}
+
+fn part2(tup: &Input) -> Result<usize, NoAnswer> {
+    let (fresh_ranges, _available) = tup;
+    fresh_set(fresh_ranges)
+        .len()
+        .ok_or_else(|| NoAnswer("fresh ID count overflows usize".to_string()))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(tup))
+    }
+
+    fn part2(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        part2(tup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day05a.txt");
+
+    #[test]
+    fn parse_example() {
+        let (fresh_ranges, available) = parse(EXAMPLE).unwrap();
+        assert_eq!(fresh_ranges, [(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(available, [1, 5, 8, 11, 17, 32]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Ok(14));
+    }
+
+    #[test]
+    fn every_id_fresh() {
+        let err = part2(&parse("0-18446744073709551615\n\n1\n").unwrap()).unwrap_err();
+        assert_eq!(err.0, "fresh ID count overflows usize");
+    }
+}
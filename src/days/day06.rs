@@ -0,0 +1,197 @@
+use std::fmt::Display;
+
+use crate::parse::{Line, lines};
+use crate::{NoAnswer, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ops {
+    Add,
+    Mul,
+}
+
+// Splits the worksheet into its number rows and the operator row underneath them.
+fn split_worksheet(s: &str) -> Result<(Vec<Line<'_>>, Line<'_>), ParseError> {
+    let mut tmp: Vec<Line> = lines(s).collect();
+    match tmp.pop() {
+        Some(ops) if !tmp.is_empty() => Ok((tmp, ops)),
+        _ => Err(ParseError::end_of_input(
+            s,
+            "expected rows of numbers above a row of operators",
+        )),
+    }
+}
+
+fn parse(s: &str) -> Result<Vec<(Ops, Vec<i64>)>, ParseError> {
+    let (num_lines, op_line) = split_worksheet(s)?;
+
+    // Handle operators
+    let ops = op_line
+        .text
+        .split_whitespace()
+        .map(|o| match o {
+            "+" => Ok(Ops::Add),
+            "*" => Ok(Ops::Mul),
+            _ => Err(op_line.error(o, "expected `+` or `*`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut nums = vec![];
+    for line in &num_lines {
+        let row = line
+            .text
+            .split_whitespace()
+            .map(|n| line.parse::<i64>(n))
+            .collect::<Result<Vec<_>, _>>()?;
+        if row.len() != ops.len() {
+            return Err(line.error(
+                line.text,
+                format!("expected {} numbers, one per problem", ops.len()),
+            ));
+        }
+        nums.push(row);
+    }
+
+    let mut output = vec![];
+    let mut vnums = vec![vec![0; nums.len()]; nums[0].len()];
+    for i in 0..nums[0].len() {
+        for j in 0..nums.len() {
+            vnums[i][j] = nums[j][i];
+        }
+    }
+    for (i, col) in vnums.iter().enumerate() {
+        output.push((ops[i], col.clone()));
+    }
+    Ok(output)
+}
+
+fn part1(rows: &[(Ops, Vec<i64>)]) -> i64 {
+    let mut output = 0;
+    for col in rows {
+        output += match col.0 {
+            Ops::Add => col.1.iter().sum::<i64>(),
+            Ops::Mul => col.1.iter().product::<i64>(),
+        }
+    }
+    output
+}
+
+fn parse2(s: &str) -> Result<Vec<(Ops, Vec<i64>)>, ParseError> {
+    let (num_lines, op_line) = split_worksheet(s)?;
+    let tmp: Vec<Vec<char>> = num_lines
+        .iter()
+        .map(|line| line.text.chars().collect())
+        .collect();
+
+    let ncols = tmp.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut vnums: Vec<Vec<i64>> = vec![];
+    let mut problem_nums = vec![];
+    for c in 0..ncols {
+        let mut num = vec![];
+        for row in &tmp {
+            // Rows may be ragged if trailing spaces were trimmed
+            num.push(*row.get(c).unwrap_or(&' '));
+        }
+        let num_str = num.into_iter().collect::<String>().trim().to_string();
+        if !num_str.is_empty() {
+            let n = num_str.parse::<i64>().map_err(|_| {
+                ParseError::new(
+                    num_lines[0].number,
+                    c + 1,
+                    &num_str,
+                    "expected a column of digits",
+                )
+            })?;
+            problem_nums.push(n);
+        } else {
+            // Done with problem
+            vnums.push(std::mem::take(&mut problem_nums));
+        }
+    }
+    if !problem_nums.is_empty() {
+        vnums.push(std::mem::take(&mut problem_nums));
+    }
+
+    // Indexes of operators indicate last number in a problem
+    let mut ops = vec![];
+    for (i, c) in op_line.text.char_indices() {
+        match c {
+            '+' => ops.push(Ops::Add),
+            '*' => ops.push(Ops::Mul),
+            ' ' => (),
+            _ => return Err(op_line.char_error(i, "expected `+` or `*`")),
+        };
+    }
+    if ops.len() != vnums.len() {
+        return Err(op_line.error(
+            op_line.text,
+            format!("expected {} operators, one per problem", vnums.len()),
+        ));
+    }
+
+    let mut output = vec![];
+    for i in 0..ops.len() {
+        output.push((ops[i], vnums[i].clone()));
+    }
+    Ok(output)
+}
+
+fn part2(rows: &[(Ops, Vec<i64>)]) -> i64 {
+    let mut output = 0;
+    for col in rows {
+        output += match col.0 {
+            Ops::Add => col.1.iter().sum::<i64>(),
+            Ops::Mul => col.1.iter().product::<i64>(),
+        }
+    }
+    output
+}
+
+// The worksheet read row-wise for part 1, and column-wise for part 2.
+type Input = (Vec<(Ops, Vec<i64>)>, Vec<(Ops, Vec<i64>)>);
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse(input)?, parse2(input)?))
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(&map.0))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(&map.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day06a.txt");
+
+    #[test]
+    fn parse_example() {
+        let rows = parse(EXAMPLE).unwrap();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], (Ops::Mul, vec![123, 45, 6]));
+
+        let columns = parse2(EXAMPLE).unwrap();
+        assert_eq!(columns.len(), 4);
+        assert_eq!(columns[0], (Ops::Mul, vec![1, 24, 356]));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 4277556);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse2(EXAMPLE).unwrap()), 3263827);
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use crate::grid::Grid;
+use crate::parse::lines;
+use crate::{NoAnswer, ParseError, Solution};
+
+fn parse(s: &str) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(s, |c| match c {
+        '.' | '^' | 'S' => Ok(c),
+        _ => Err("expected `.`, `^` or `S`"),
+    })?;
+    if !map.row(0).contains(&'S') {
+        let first = lines(s).next().expect("grid has a first row");
+        return Err(first.error(first.text, "expected the `S` start in the first row"));
+    }
+    // A splitter on either edge would send half its beam off the manifold
+    let edges = [0, map.width() - 1];
+    for (line, row) in lines(s).zip(map.rows()) {
+        if let Some(&x) = edges.iter().find(|&&x| row[x] == '^') {
+            return Err(line.char_error(x, "expected no splitter at the edge"));
+        }
+    }
+    Ok(map)
+}
+
+fn part1(map: &Grid<char>) -> i64 {
+    // Start at the 'S'
+    let (start_idx, _) = map
+        .row(0)
+        .iter()
+        .enumerate()
+        .find(|&(_i, c)| *c == 'S')
+        .unwrap();
+    let mut water_idxs = vec![start_idx];
+    let mut cntr = 0;
+    for row in map.rows().skip(1) {
+        let mut new_water_idxs = HashSet::new();
+        for wi in &water_idxs {
+            if row[*wi] == '^' {
+                new_water_idxs.insert(wi - 1);
+                new_water_idxs.insert(wi + 1);
+                cntr += 1;
+            } else {
+                new_water_idxs.insert(*wi);
+            }
+        }
+        water_idxs = new_water_idxs.into_iter().collect();
+    }
+    cntr
+}
+
+fn part2(map: &Grid<char>) -> usize {
+    // Start at the 'S'
+    let (start_idx, _) = map
+        .row(0)
+        .iter()
+        .enumerate()
+        .find(|&(_i, c)| *c == 'S')
+        .unwrap();
+    let mut paths = HashMap::new();
+    // hashmap key is idx, value is count of paths that lead here.
+    paths.insert(start_idx, 1);
+    for row in map.rows().skip(1) {
+        let mut new_paths = HashMap::new();
+        for i in paths.keys() {
+            let count = paths[i];
+            if row[*i] == '^' {
+                if let Some(v) = new_paths.get_mut(&(i - 1)) {
+                    *v += count;
+                } else {
+                    new_paths.insert(i - 1, count);
+                }
+                if let Some(v) = new_paths.get_mut(&(i + 1)) {
+                    *v += count;
+                } else {
+                    new_paths.insert(i + 1, count);
+                }
+            } else {
+                if let Some(v) = new_paths.get_mut(i) {
+                    *v += count;
+                } else {
+                    new_paths.insert(*i, count);
+                }
+            }
+        }
+        paths = new_paths;
+    }
+    paths.values().sum::<usize>()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(map))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day07a.txt");
+
+    #[test]
+    fn parse_example() {
+        let map = parse(EXAMPLE).unwrap();
+        assert_eq!(map.height(), 16);
+        assert_eq!(map[(7, 0)], 'S');
+    }
+
+    #[test]
+    fn edge_splitter() {
+        let err = parse("S.\n^.\n..\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "^"));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 40);
+    }
+}
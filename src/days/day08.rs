@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use crate::answers::InputKind;
+use crate::disjoint_set::DisjointSet;
+use crate::pairs::{closest_pairs, point_mst};
+use crate::parse::lines;
+use crate::point::Point3;
+use crate::{NoAnswer, ParseError, Solution};
+
+fn parse(s: &str) -> Result<Vec<Point3>, ParseError> {
+    lines(s)
+        .map(|line| Point3::parse(&line, line.text))
+        .collect()
+}
+
+fn part1(tup: &[Point3], n_connections: usize) -> usize {
+    let mut circuits = DisjointSet::new(tup.len());
+    for e in closest_pairs(tup).take(n_connections) {
+        circuits.union(e.a, e.b);
+    }
+    circuits.component_sizes().iter().take(3).product()
+}
+
+// The connection that finally joins everything into one circuit is the last edge Kruskal adds.
+fn part2(tup: &[Point3]) -> i64 {
+    match point_mst(tup).completing {
+        Some(e) => tup[e.a].x * tup[e.b].x,
+        None => 0,
+    }
+}
+
+// The example connects its junction boxes 10 times, the real input 1000 times.
+fn n_connections(kind: InputKind) -> usize {
+    match kind {
+        InputKind::Example => 10,
+        InputKind::Real => 1000,
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    // The junction boxes and how many connections part 1 makes
+    type Input = (Vec<Point3>, usize);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_kind(input, InputKind::Real)
+    }
+
+    fn parse_kind(input: &str, kind: InputKind) -> Result<Self::Input, ParseError> {
+        Ok((parse(input)?, n_connections(kind)))
+    }
+
+    fn part1(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(&tup.0, tup.1))
+    }
+
+    fn part2(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(&tup.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day08a.txt");
+
+    #[test]
+    fn parse_example() {
+        let tup = parse(EXAMPLE).unwrap();
+        assert_eq!(tup.len(), 20);
+        assert_eq!(tup[0], Point3::new(162, 817, 812));
+        let (_, n) = Day08::parse_kind(EXAMPLE, InputKind::Example).unwrap();
+        assert_eq!(n, 10);
+    }
+
+    #[test]
+    fn part1_example() {
+        // The example only makes the 10 shortest connections
+        assert_eq!(part1(&parse(EXAMPLE).unwrap(), 10), 40);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 25272);
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::Display;
+
+use crate::compress::{CompressedGrid, Compression};
+use crate::parse::lines;
+use crate::point::Point2;
+use crate::polygon::OrthoPolygon;
+use crate::rect::largest_rect;
+use crate::search::reachable;
+use crate::{NoAnswer, ParseError, Solution};
+
+fn parse(s: &str) -> Result<Vec<Point2>, ParseError> {
+    let mut tiles: Vec<Point2> = vec![];
+    for line in lines(s) {
+        let tile = Point2::parse(&line, line.text)?;
+        // The tiles go round a loop of straight lines, so each shares a row or column with the last
+        if let Some(&prev) = tiles.last()
+            && (prev.x == tile.x) == (prev.y == tile.y)
+        {
+            return Err(line.error(line.text, format!("expected a tile in line with {}", prev)));
+        }
+        tiles.push(tile);
+    }
+    if tiles.len() < 4 {
+        return Err(ParseError::end_of_input(
+            s,
+            "expected at least four red tiles",
+        ));
+    }
+    let (first, last) = (tiles[0], tiles[tiles.len() - 1]);
+    if (first.x == last.x) == (first.y == last.y) {
+        return Err(ParseError::end_of_input(
+            s,
+            format!(
+                "expected the last tile to line up with the first, {}",
+                first
+            ),
+        ));
+    }
+    Ok(tiles)
+}
+
+fn part1(tup: &[Point2]) -> i64 {
+    largest_rect(tup, |_| true).map_or(0, |r| r.area())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Inside,
+    Loop,
+    Outside,
+}
+
+// The biggest rectangle with red tiles at opposite corners that only covers red or green tiles.
+// Compressing the coordinates leaves a small grid of cells, each a block of real tiles. Flood
+// filling from beyond the loop finds the outside cells, and prefix sums over their real areas
+// then check each candidate rectangle in constant time.
+fn part2(tup: &[Point2]) -> i64 {
+    let polygon = OrthoPolygon::new(tup.to_vec()).expect("parse checks the tiles form a loop");
+    // Pad by one on each side so the outside is connected all the way round
+    let padded = |coord: fn(&Point2) -> i64| {
+        let (lo, hi) = (tup.iter().map(coord).min(), tup.iter().map(coord).max());
+        Compression::new(
+            tup.iter()
+                .map(coord)
+                .chain([lo.unwrap() - 1, hi.unwrap() + 1]),
+        )
+    };
+    let mut cells = CompressedGrid::new(padded(|p| p.x), padded(|p| p.y), Cell::Inside);
+    for (a, b) in polygon.edges() {
+        let ((x0, y0), (x1, y1)) = (cells.cell(a).unwrap(), cells.cell(b).unwrap());
+        for y in y0.min(y1)..=y0.max(y1) {
+            for x in x0.min(x1)..=x0.max(x1) {
+                cells.grid[(x, y)] = Cell::Loop;
+            }
+        }
+    }
+    // Flood fill the outside, starting from the padding corner
+    let outside = reachable((0, 0), |&(x, y)| {
+        let grid = &cells.grid;
+        grid.neighbors4(x, y).filter(|&c| grid[c] == Cell::Inside)
+    });
+    for c in outside {
+        cells.grid[c] = Cell::Outside;
+    }
+    let outside_tiles = cells
+        .weighted(|&c| (c == Cell::Outside) as i64)
+        .prefix_sums();
+
+    largest_rect(tup, |r| {
+        let (c1, c2) = (cells.cell(r.min).unwrap(), cells.cell(r.max).unwrap());
+        outside_tiles.sum(c1, c2) == 0
+    })
+    .map_or(0, |r| r.area())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<Point2>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(tup))
+    }
+
+    fn part2(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(tup))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day09a.txt");
+
+    #[test]
+    fn parse_example() {
+        let tup = parse(EXAMPLE).unwrap();
+        assert_eq!(tup.len(), 8);
+        assert_eq!(tup[0], Point2::new(7, 1));
+    }
+
+    #[test]
+    fn parse_checks_the_loop() {
+        let err = parse("7,1\n11,1\n11,7\n9,8\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (4, "expected a tile in line with 11,7")
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 50);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 24);
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::Display;
+
+use crate::gf2::{BitVec, Gf2Error, Gf2Matrix};
+use crate::ilp::minimize;
+use crate::parse::{Line, lines};
+use crate::{NoAnswer, ParseError, Solution};
+
+type Input = (usize, u64, Vec<u64>, Vec<u64>);
+
+fn parse_machine(line: &Line) -> Result<Input, ParseError> {
+    let text = line.text;
+
+    // First part indicator lights in [...]
+    let (open, close) = match (text.find('['), text.find(']')) {
+        (Some(open), Some(close)) if open < close => (open, close),
+        _ => return Err(line.error(text, "expected indicator lights like `[.##.]`")),
+    };
+    let lights = &text[open + 1..close];
+    if lights.len() > 64 {
+        return Err(line.error(lights, "expected at most 64 indicator lights"));
+    }
+    let nlights = lights.len();
+    let mut lightint = 0_u64;
+    for (i, c) in lights.char_indices() {
+        match c {
+            '#' => lightint ^= 1_u64 << i,
+            '.' => (),
+            _ => return Err(line.char_error(open + 1 + i, "expected `.` or `#`")),
+        }
+    }
+
+    // Then parse wiring button schematics in (...)
+    let rest = &text[close + 1..];
+    let (brace, end) = match (rest.find('{'), rest.find('}')) {
+        (Some(brace), Some(end)) if brace < end => (brace, end),
+        _ => return Err(line.error(rest, "expected joltage requirements like `{3,5,4,7}`")),
+    };
+    let mut buttons = vec![];
+    for tmp in rest[..brace].split_whitespace() {
+        let wiring = tmp
+            .strip_prefix('(')
+            .and_then(|t| t.strip_suffix(')'))
+            .ok_or_else(|| line.error(tmp, "expected a button like `(1,3)`"))?;
+        let mut buttonint = 0_u64;
+        for i in wiring.split(',') {
+            let light = line.parse::<usize>(i)?;
+            if light >= nlights {
+                return Err(line.error(i, format!("expected a light index below {}", nlights)));
+            }
+            buttonint ^= 1_u64 << light;
+        }
+        buttons.push(buttonint);
+    }
+
+    // Finally parse joltage requirements in {...}
+    let joltage = &rest[brace + 1..end];
+    let joltages = joltage
+        .split(',')
+        .map(|j| line.parse::<u64>(j.trim()))
+        .collect::<Result<Vec<u64>, _>>()?;
+    if joltages.len() != nlights {
+        return Err(line.error(
+            joltage,
+            format!("expected {} joltage requirements, one per light", nlights),
+        ));
+    }
+    let trailing = rest[end + 1..].trim();
+    if !trailing.is_empty() {
+        return Err(line.error(trailing, "unexpected text after joltage requirements"));
+    }
+
+    Ok((nlights, lightint, buttons, joltages))
+}
+
+fn parse(s: &str) -> Result<Vec<Input>, ParseError> {
+    lines(s).map(|line| parse_machine(&line)).collect()
+}
+
+// Fewest presses to light the pattern. Pressing a button twice undoes it, so this is the
+// lowest-weight solution of `B x = lights` over GF(2), with the buttons as the columns of `B`.
+fn part1(machines: &[Input]) -> Result<usize, NoAnswer> {
+    let mut output = 0;
+    for (k, m) in machines.iter().enumerate() {
+        let buttons: Vec<BitVec> = m.2.iter().map(|&b| BitVec::from_u64(b, m.0)).collect();
+        let b = Gf2Matrix::from_columns(&buttons, m.0);
+        let x = match b.min_weight_solution(&BitVec::from_u64(m.1, m.0)) {
+            Ok(x) => x,
+            Err(Gf2Error::NoSolution) => {
+                return Err(NoAnswer(format!(
+                    "machine {}: no way to light the pattern",
+                    k + 1
+                )));
+            }
+            Err(e) => return Err(NoAnswer(format!("machine {}: {}", k + 1, e))),
+        };
+        output += x.count_ones();
+    }
+    Ok(output)
+}
+
+// Fewest presses so each counter reaches its joltage. Button `j` adds one to every counter it's
+// wired to, so this is `min sum(x)` subject to `A x = joltages` over non-negative integers.
+fn part2(machines: &[Input]) -> Result<i64, NoAnswer> {
+    let mut output = 0;
+    for (k, m) in machines.iter().enumerate() {
+        let (buttons, joltages) = (&m.2, &m.3);
+        let a: Vec<Vec<i64>> = (0..m.0)
+            .map(|i| buttons.iter().map(|b| ((b >> i) & 1) as i64).collect())
+            .collect();
+        let b: Vec<i64> = joltages.iter().map(|&j| j as i64).collect();
+        let optimum = minimize(&a, &b, &vec![1; buttons.len()])
+            .map_err(|e| NoAnswer(format!("machine {}: {}", k + 1, e)))?;
+        output += optimum.value;
+    }
+    Ok(output)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<Input>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<impl Display, NoAnswer> {
+        part1(machines)
+    }
+
+    fn part2(machines: &Self::Input) -> Result<impl Display, NoAnswer> {
+        part2(machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day10a.txt");
+
+    #[test]
+    fn parse_example() {
+        let machines = parse(EXAMPLE).unwrap();
+        assert_eq!(machines.len(), 3);
+        assert_eq!(
+            machines[0],
+            (
+                4,
+                0b0110,
+                vec![0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011],
+                vec![3, 5, 4, 7]
+            )
+        );
+    }
+
+    #[test]
+    fn parse_reports_bad_light_index() {
+        let err = parse("[.##.] (3) (1,4) {3,5,4,7}").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 15, "4"));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Ok(7));
+    }
+
+    #[test]
+    fn unlit_pattern_has_no_answer() {
+        // Both buttons toggle both lights, so one can never be lit without the other
+        let machines = parse("[.#] (0,1) (0,1) {1,1}").unwrap();
+        let err = part1(&machines).unwrap_err();
+        assert_eq!(err.0, "machine 1: no way to light the pattern");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Ok(33));
+    }
+
+    #[test]
+    fn unreachable_joltage_has_no_answer() {
+        // The only button adds to both counters, so they can't end up different
+        let machines = parse("[.#] (0,1) {1,2}").unwrap();
+        let err = part2(&machines).unwrap_err();
+        assert_eq!(err.0, "machine 1: no integer solution");
+    }
+}
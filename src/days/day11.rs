@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::biguint::BigUint;
+use crate::graph::{Graph, GraphBuilder, NodeId, count_paths_via, path_counts_from, topo_sort};
+use crate::parse::{Line, lines};
+use crate::{NoAnswer, ParseError, Solution};
+
+type Input = Graph;
+
+fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut builder = GraphBuilder::new();
+    // The line each device is listed on, to point at if it's part of a loop
+    let mut listed: HashMap<&str, Line> = HashMap::new();
+    for line in lines(s) {
+        let (name, outputs) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| line.error(line.text, "expected a device like `aaa: you hhh`"))?;
+        let name = name.trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(line.error(name, "expected a device name before `:`"));
+        }
+        if listed.insert(name, line).is_some() {
+            return Err(line.error(name, "device is listed more than once"));
+        }
+        builder.node(name);
+        for output in outputs.split_whitespace() {
+            builder.edge(name, output);
+        }
+    }
+    let map = builder.build();
+    // Path counts are only finite without loops, so turn those away here
+    if let Err(cycle) = topo_sort(&map) {
+        let names: Vec<&str> = cycle.nodes.iter().map(|&u| map.name(u)).collect();
+        let line = listed[names[0]];
+        let message = format!("device loop: {} -> {}", names.join(" -> "), names[0]);
+        return Err(line.error(names[0], message));
+    }
+    Ok(map)
+}
+
+// Number of paths from `start` to each device, indexed by id. All zero if `start` doesn't exist.
+fn count_ways_to_nodes(start: &str, map: &Input) -> Vec<BigUint> {
+    match map.id(start) {
+        Some(start) => path_counts_from(map, start).expect("parse turns away device loops"),
+        None => vec![BigUint::zero(); map.len()],
+    }
+}
+
+// Paths from `from` to `to` through all of `via`, zero if any of the devices don't exist.
+fn count_paths_between(map: &Input, from: &str, to: &str, via: &[&str]) -> BigUint {
+    let (Some(from), Some(to)) = (map.id(from), map.id(to)) else {
+        return BigUint::zero();
+    };
+    let Some(via) = via
+        .iter()
+        .map(|&w| map.id(w))
+        .collect::<Option<Vec<NodeId>>>()
+    else {
+        return BigUint::zero();
+    };
+    count_paths_via(map, from, to, &via).expect("parse turns away device loops")
+}
+
+// The device graph as Graphviz DOT, with the devices the puzzle mentions highlighted and each edge
+// labelled by the number of paths from `start` that reach its source.
+pub fn to_dot(map: &Input, start: &str) -> String {
+    let ways = count_ways_to_nodes(start, map);
+    let highlight: Vec<NodeId> = ["you", "svr", "fft", "dac", "out"]
+        .iter()
+        .filter_map(|&name| map.id(name))
+        .collect();
+    map.to_dot(&highlight, |u, _| {
+        let w = &ways[u as usize];
+        (!w.is_zero()).then(|| w.to_string())
+    })
+}
+
+fn part1(map: &Input) -> BigUint {
+    count_paths_between(map, "you", "out", &[])
+}
+
+fn part2(map: &Input) -> BigUint {
+    count_paths_between(map, "svr", "out", &["fft", "dac"])
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(map))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Digraph;
+
+    const EXAMPLE1: &str = include_str!("../../inputs/day11a.txt");
+    // Part 2 has its own example, starting from `svr` instead of `you`
+    const EXAMPLE2: &str = include_str!("../../inputs/day11b.txt");
+
+    #[test]
+    fn parse_example() {
+        let map = parse(EXAMPLE1).unwrap();
+        // Ten listed devices plus `out`
+        assert_eq!(map.len(), 11);
+        let you = map.id("you").unwrap();
+        let outputs: Vec<&str> = map.successors(you).iter().map(|&u| map.name(u)).collect();
+        assert_eq!(outputs, ["bbb", "ccc"]);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE1).unwrap()), BigUint::from(5));
+    }
+
+    #[test]
+    fn ways_to_nodes() {
+        let map = parse(EXAMPLE1).unwrap();
+        let ways = count_ways_to_nodes("you", &map);
+        assert_eq!(ways[map.id("out").unwrap() as usize], BigUint::from(5));
+        assert_eq!(ways[map.id("you").unwrap() as usize], BigUint::one());
+        assert!(ways[map.id("aaa").unwrap() as usize].is_zero());
+    }
+
+    #[test]
+    fn loops_are_reported() {
+        let err = parse("you: bbb\nbbb: ccc\nccc: bbb out").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (2, "bbb"));
+        assert_eq!(err.message, "device loop: bbb -> ccc -> bbb");
+    }
+
+    #[test]
+    fn dot_labels() {
+        let dot = to_dot(&parse(EXAMPLE1).unwrap(), "you");
+        assert!(dot.contains(r#""you" [style=filled, fillcolor=gold];"#));
+        assert!(dot.contains(r#""eee" -> "out" [label="2"];"#));
+        assert!(dot.contains(r#""aaa" -> "you";"#));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE2).unwrap()), BigUint::from(2));
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use crate::packing::{Infeasible, Placement, pack};
+use crate::parse::{Line, lines};
+use crate::polyomino::Polyomino;
+use crate::{NoAnswer, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Tree {
+    dims: (usize, usize),
+    cnts: Vec<usize>,
+}
+
+impl Tree {
+    fn new(dims: (usize, usize), cnts: Vec<usize>) -> Self {
+        Self { dims, cnts }
+    }
+
+    // Fits all the presents under the tree, or says why they can't.
+    fn pack(&self, pieces: &[Polyomino]) -> Result<Vec<Placement>, Infeasible> {
+        pack(self.dims.0, self.dims.1, pieces, &self.cnts)
+    }
+}
+
+type Input = (Vec<Polyomino>, Vec<Tree>);
+
+fn parse(s: &str) -> Result<Input, ParseError> {
+    let mut shapes = vec![];
+    let mut trees = vec![];
+    let mut liter = lines(s).peekable();
+    // Shapes come first, each a `N:` header over its rows and ended by a blank line
+    while let Some(header) = liter.next_if(|line| line.text.ends_with(':')) {
+        let index = shapes.len();
+        if header.text != format!("{}:", index) {
+            return Err(header.error(header.text, format!("expected shape index `{}:`", index)));
+        }
+        let rows: Vec<Line> = liter
+            .by_ref()
+            .take_while(|line| !line.text.is_empty())
+            .collect();
+        if rows.is_empty() {
+            return Err(header.error(header.text, "expected rows of `#` and `.` below"));
+        }
+        shapes.push(Polyomino::from_lines(&rows)?);
+    }
+    if shapes.is_empty() {
+        let message = "expected a present shape like `0:`";
+        return Err(match liter.peek() {
+            Some(line) => line.error(line.text, message),
+            None => ParseError::end_of_input(s, message),
+        });
+    }
+
+    for line in liter {
+        let (dims, cnts) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| line.error(line.text, "expected a region like `12x5: 1 0 1 0 2 2`"))?;
+        let (width, height) = dims
+            .split_once('x')
+            .ok_or_else(|| line.error(dims, "expected dimensions like `12x5`"))?;
+        let width = line.parse::<usize>(width)?;
+        let height = line.parse::<usize>(height)?;
+        let cnts = cnts
+            .split_whitespace()
+            .map(|n| line.parse::<usize>(n))
+            .collect::<Result<Vec<usize>, _>>()?;
+        if cnts.len() != shapes.len() {
+            return Err(line.error(
+                line.text,
+                format!("expected {} present counts, one per shape", shapes.len()),
+            ));
+        }
+        trees.push(Tree::new((width, height), cnts));
+    }
+
+    Ok((shapes, trees))
+}
+
+fn part1(tup: &Input) -> i64 {
+    let (shapes, trees) = tup;
+    trees
+        .iter()
+        .filter(|tree| tree.pack(shapes).is_ok())
+        .count() as i64
+}
+
+fn part2(_tup: &Input) -> i64 {
+    0
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part1(tup))
+    }
+
+    fn part2(tup: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(part2(tup))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/day12a.txt");
+
+    #[test]
+    fn parse_example() {
+        let (shapes, trees) = parse(EXAMPLE).unwrap();
+        assert_eq!(shapes.len(), 6);
+        assert_eq!(shapes[4].area(), 7);
+        assert_eq!(trees.len(), 3);
+        assert_eq!(trees[1].dims, (12, 5));
+        assert_eq!(trees[1].cnts, [1, 0, 1, 0, 2, 2]);
+    }
+
+    #[test]
+    fn any_shapes() {
+        let input = "0:\n####\n\n1:\n#.\n##\n\n6x2: 1 2\n3x3: 2 1\n";
+        let (shapes, trees) = parse(input).unwrap();
+        assert_eq!(shapes[0].bounds(), (4, 1));
+        assert_eq!(shapes[1].to_string(), "#.\n##\n");
+        assert_eq!(part1(&(shapes, trees)), 1);
+
+        let err = parse("12x5: 1 0\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (1, "expected a present shape like `0:`")
+        );
+        let err = parse("0:\n#\n\n2:\n#\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (4, "2:"));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn witness_and_proof() {
+        let (shapes, trees) = parse(EXAMPLE).unwrap();
+        assert_eq!(shapes[0].orientations().len(), 8);
+        let placed = trees[1].pack(&shapes).unwrap();
+        assert_eq!(placed.len(), 6);
+        let covered: usize = placed.iter().map(|p| p.cells.len()).sum();
+        assert_eq!(covered, 42);
+        // The extra present leaves an area that would fit, but no arrangement does
+        let result = trees[2].pack(&shapes);
+        assert!(matches!(result, Err(Infeasible::Exhausted { .. })));
+    }
+}
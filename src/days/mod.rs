@@ -0,0 +1,33 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+use crate::runner::Day;
+
+pub const ALL: [Day; 12] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+];
+
+pub fn get(day: u32) -> Option<Day> {
+    ALL.iter().find(|d| d.day == day).copied()
+}
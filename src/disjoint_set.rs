@@ -0,0 +1,120 @@
+// Union-find over the elements `0..n`, with path compression and union by size.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    // `n` singleton components.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    // Representative of the component containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Point everything on the way straight at the root
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    // Merges the components of `a` and `b`. Returns false if they were already the same one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Size of the component containing `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    pub fn num_components(&self) -> usize {
+        self.components
+    }
+
+    // Size of every component, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|x| self.size[x])
+            .collect();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    // The members of each component, in order of their smallest element.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut output: Vec<Vec<usize>> = vec![];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = output.len();
+                output.push(vec![]);
+            }
+            output[index[root]].push(x);
+        }
+        output.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions() {
+        let mut ds = DisjointSet::new(6);
+        assert_eq!(ds.num_components(), 6);
+        assert!(ds.union(0, 1));
+        assert!(ds.union(2, 3));
+        assert!(ds.union(1, 3));
+        assert!(!ds.union(0, 2));
+        assert!(ds.same(0, 3));
+        assert!(!ds.same(0, 4));
+        assert_eq!(ds.size_of(2), 4);
+        assert_eq!(ds.num_components(), 3);
+        assert_eq!(ds.component_sizes(), [4, 1, 1]);
+        assert_eq!(
+            ds.components().collect::<Vec<_>>(),
+            [vec![0, 1, 2, 3], vec![4], vec![5]]
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::BitXorAssign;
+
+use crate::search::bfs;
+
+// Largest nullity `min_weight_solution` will walk, 2^32 candidates being several seconds already.
+pub const MAX_NULLITY: usize = 32;
+// Largest rank it will search the reachable patterns of instead. Every pattern seen is kept, so
+// this is bounded by memory rather than time.
+pub const MAX_RANK: usize = 24;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gf2Error {
+    NoSolution,
+    // Too many free variables to try every solution, and too many patterns to search instead
+    TooLarge { rank: usize, nullity: usize },
+}
+
+impl fmt::Display for Gf2Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gf2Error::NoSolution => write!(f, "no solution"),
+            Gf2Error::TooLarge { rank, nullity } => write!(
+                f,
+                "rank {} and nullity {} are too large to search, the limits are {} and {}",
+                rank, nullity, MAX_RANK, MAX_NULLITY
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Gf2Error {}
+
+// Fixed-length vector over GF(2), packed 64 bits to a word. Bits past `len` are always zero.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    // The low `len` bits of `bits`, bit `i` becoming element `i`.
+    pub fn from_u64(bits: u64, len: usize) -> Self {
+        let mut v = Self::new(len);
+        for i in (0..len.min(64)).filter(|&i| bits >> i & 1 == 1) {
+            v.set(i, true);
+        }
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(
+            i < self.len,
+            "bit {} out of range for length {}",
+            i,
+            self.len
+        );
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(
+            i < self.len,
+            "bit {} out of range for length {}",
+            i,
+            self.len
+        );
+        let mask = 1 << (i % 64);
+        if value {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    pub fn flip(&mut self, i: usize) {
+        let value = self.get(i);
+        self.set(i, !value);
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    // Hamming weight.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    // Indices of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|&i| self.get(i))
+    }
+
+    pub fn dot(&self, other: &BitVec) -> bool {
+        let ones: u32 = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones())
+            .sum();
+        ones % 2 == 1
+    }
+}
+
+impl BitXorAssign<&BitVec> for BitVec {
+    fn bitxor_assign(&mut self, rhs: &BitVec) {
+        assert_eq!(self.len, rhs.len, "length mismatch");
+        for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+            *a ^= b;
+        }
+    }
+}
+
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.len {
+            write!(f, "{}", if self.get(i) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+// Matrix over GF(2), stored as bit-packed rows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gf2Matrix {
+    rows: Vec<BitVec>,
+    cols: usize,
+}
+
+impl Gf2Matrix {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows: vec![BitVec::new(cols); rows],
+            cols,
+        }
+    }
+
+    // A matrix with the given vectors as its columns, each `rows` long.
+    pub fn from_columns(columns: &[BitVec], rows: usize) -> Self {
+        let mut m = Self::new(rows, columns.len());
+        for (j, column) in columns.iter().enumerate() {
+            for i in column.ones() {
+                m.set(i, j, true);
+            }
+        }
+        m
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        self.rows[r].get(c)
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: bool) {
+        self.rows[r].set(c, value)
+    }
+
+    pub fn row(&self, r: usize) -> &BitVec {
+        &self.rows[r]
+    }
+
+    pub fn column(&self, c: usize) -> BitVec {
+        let mut output = BitVec::new(self.num_rows());
+        for (i, row) in self.rows.iter().enumerate() {
+            output.set(i, row.get(c));
+        }
+        output
+    }
+
+    pub fn mul_vec(&self, x: &BitVec) -> BitVec {
+        let mut output = BitVec::new(self.num_rows());
+        for (i, row) in self.rows.iter().enumerate() {
+            output.set(i, row.dot(x));
+        }
+        output
+    }
+
+    // Puts the matrix into reduced row echelon form, returning the pivot column of each nonzero
+    // row. Those rows come first and any zero rows are left at the bottom.
+    pub fn row_reduce(&mut self) -> Vec<usize> {
+        let mut pivots = vec![];
+        for c in 0..self.cols {
+            let r = pivots.len();
+            if r == self.rows.len() {
+                break;
+            }
+            let Some(found) = (r..self.rows.len()).find(|&i| self.rows[i].get(c)) else {
+                continue;
+            };
+            self.rows.swap(r, found);
+            let pivot_row = self.rows[r].clone();
+            for (i, row) in self.rows.iter_mut().enumerate() {
+                if i != r && row.get(c) {
+                    *row ^= &pivot_row;
+                }
+            }
+            pivots.push(c);
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    // A basis for the solutions of `A x = 0`, one vector per free column.
+    pub fn nullspace(&self) -> Vec<BitVec> {
+        let mut m = self.clone();
+        let pivots = m.row_reduce();
+        let mut basis = vec![];
+        for free in (0..self.cols).filter(|c| !pivots.contains(c)) {
+            let mut x = BitVec::new(self.cols);
+            x.set(free, true);
+            for (r, &c) in pivots.iter().enumerate() {
+                if m.rows[r].get(free) {
+                    x.set(c, true);
+                }
+            }
+            basis.push(x);
+        }
+        basis
+    }
+
+    // Some solution of `A x = b` with every free variable zero, None if there's none at all.
+    pub fn solve(&self, b: &BitVec) -> Option<BitVec> {
+        assert_eq!(b.len(), self.num_rows(), "length mismatch");
+        // Reduce the augmented matrix `[A | b]`
+        let mut augmented = Gf2Matrix::new(self.num_rows(), self.cols + 1);
+        for i in 0..self.num_rows() {
+            for j in self.rows[i].ones() {
+                augmented.set(i, j, true);
+            }
+            augmented.set(i, self.cols, b.get(i));
+        }
+        let pivots = augmented.row_reduce();
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+        let mut x = BitVec::new(self.cols);
+        for (r, &c) in pivots.iter().enumerate() {
+            x.set(c, augmented.get(r, self.cols));
+        }
+        Some(x)
+    }
+
+    // A solution of `A x = b` with the fewest ones. Pressing the same column twice cancels out
+    // and a zero column does nothing, so only the first copy of each distinct nonzero column is
+    // kept. Then whichever is smaller decides how: with few free variables every coset member is
+    // walked, otherwise the patterns the columns can reach are searched breadth first.
+    pub fn min_weight_solution(&self, b: &BitVec) -> Result<BitVec, Gf2Error> {
+        let columns: Vec<BitVec> = (0..self.cols).map(|c| self.column(c)).collect();
+        let mut seen = HashSet::new();
+        let kept: Vec<usize> = (0..self.cols)
+            .filter(|&c| !columns[c].is_zero() && seen.insert(&columns[c]))
+            .collect();
+        let distinct: Vec<BitVec> = kept.iter().map(|&c| columns[c].clone()).collect();
+        let reduced = Gf2Matrix::from_columns(&distinct, self.num_rows());
+        let x = reduced.solve(b).ok_or(Gf2Error::NoSolution)?;
+        let rank = reduced.rank();
+        let nullity = kept.len() - rank;
+        let walk = nullity <= MAX_NULLITY && (nullity <= rank || rank > MAX_RANK);
+        let y = if walk {
+            reduced.walk_coset(x)
+        } else if rank <= MAX_RANK {
+            reduced.fewest_columns(b).ok_or(Gf2Error::NoSolution)?
+        } else {
+            return Err(Gf2Error::TooLarge { rank, nullity });
+        };
+        let mut output = BitVec::new(self.cols);
+        for i in y.ones() {
+            output.set(kept[i], true);
+        }
+        Ok(output)
+    }
+
+    // The lightest of `x` plus any nullspace vector. Walks every one in Gray code order, one xor
+    // per step, so it's exponential in the nullity rather than the number of columns.
+    fn walk_coset(&self, mut x: BitVec) -> BitVec {
+        let basis = self.nullspace();
+        let mut best = x.clone();
+        for step in 1..1_u64 << basis.len() {
+            x ^= &basis[step.trailing_zeros() as usize];
+            if x.count_ones() < best.count_ones() {
+                best = x.clone();
+            }
+        }
+        best
+    }
+
+    // The fewest distinct columns summing to `b`, by breadth-first search from zero over the
+    // 2^rank sums the columns can reach. Each step of the path adds one column.
+    fn fewest_columns(&self, b: &BitVec) -> Option<BitVec> {
+        let columns: Vec<BitVec> = (0..self.cols).map(|c| self.column(c)).collect();
+        let index: HashMap<&BitVec, usize> = columns.iter().zip(0..).collect();
+        let path = bfs(
+            BitVec::new(self.num_rows()),
+            |sum| {
+                let mut next = vec![sum.clone(); columns.len()];
+                for (n, c) in next.iter_mut().zip(&columns) {
+                    *n ^= c;
+                }
+                next
+            },
+            |sum| sum == b,
+        )?;
+        let mut x = BitVec::new(self.cols);
+        for step in path.states.windows(2) {
+            let mut column = step[0].clone();
+            column ^= &step[1];
+            x.flip(index[&column]);
+        }
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_vectors() {
+        let mut v = BitVec::from_u64(0b1011, 70);
+        v.set(69, true);
+        v.flip(1);
+        assert_eq!(v.ones().collect::<Vec<_>>(), [0, 3, 69]);
+        assert_eq!(v.count_ones(), 3);
+        assert_eq!(BitVec::from_u64(0b1011, 4).to_string(), "1101");
+        v ^= &v.clone();
+        assert!(v.is_zero());
+    }
+
+    #[test]
+    fn solves_and_minimises() {
+        // The first example machine: buttons (3) (1,3) (2) (2,3) (0,2) (0,1), lights [.##.]
+        let buttons: Vec<BitVec> = [0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011]
+            .iter()
+            .map(|&b| BitVec::from_u64(b, 4))
+            .collect();
+        let m = Gf2Matrix::from_columns(&buttons, 4);
+        assert_eq!(m.rank(), 4);
+        let nullspace = m.nullspace();
+        assert_eq!(nullspace.len(), 2);
+        for x in &nullspace {
+            assert!(m.mul_vec(x).is_zero());
+        }
+        let target = BitVec::from_u64(0b0110, 4);
+        assert_eq!(m.mul_vec(&m.solve(&target).unwrap()), target);
+        let best = m.min_weight_solution(&target).unwrap();
+        assert_eq!((m.mul_vec(&best), best.count_ones()), (target, 2));
+
+        let dependent = Gf2Matrix::from_columns(&[BitVec::from_u64(0b11, 2)], 2);
+        assert_eq!(dependent.solve(&BitVec::from_u64(0b01, 2)), None);
+        assert_eq!(
+            dependent.min_weight_solution(&BitVec::from_u64(0b01, 2)),
+            Err(Gf2Error::NoSolution)
+        );
+    }
+
+    #[test]
+    fn wide_machines() {
+        // `[#.] (0) (1) (0,1)` with those three buttons repeated 13 times: 37 free variables,
+        // but only three distinct columns
+        let buttons: Vec<BitVec> = [0b01, 0b10, 0b11]
+            .iter()
+            .cycle()
+            .take(39)
+            .map(|&b| BitVec::from_u64(b, 2))
+            .collect();
+        let m = Gf2Matrix::from_columns(&buttons, 2);
+        assert_eq!(m.nullspace().len(), 37);
+        let best = m.min_weight_solution(&BitVec::from_u64(0b01, 2)).unwrap();
+        assert_eq!(best.ones().collect::<Vec<_>>(), [0]);
+
+        // Every pair of ten lights: 45 distinct columns of rank 9, so 36 free variables
+        let pairs: Vec<BitVec> = (0..10)
+            .flat_map(|i| (i + 1..10).map(move |j| BitVec::from_u64(1 << i | 1 << j, 10)))
+            .collect();
+        let m = Gf2Matrix::from_columns(&pairs, 10);
+        assert_eq!(m.rank(), 9);
+        let target = BitVec::from_u64(0b11_1100_0011, 10);
+        let best = m.min_weight_solution(&target).unwrap();
+        assert_eq!((m.mul_vec(&best), best.count_ones()), (target, 3));
+        assert_eq!(
+            m.min_weight_solution(&BitVec::from_u64(0b111, 10)),
+            Err(Gf2Error::NoSolution)
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::biguint::BigUint;
+
+// Nodes are dense indices `0..num_nodes`.
+pub type NodeId = u32;
+
+// A directed graph the algorithms below can walk.
+pub trait Digraph {
+    fn num_nodes(&self) -> usize;
+    fn successors(&self, u: NodeId) -> &[NodeId];
+}
+
+impl Digraph for [Vec<NodeId>] {
+    fn num_nodes(&self) -> usize {
+        self.len()
+    }
+
+    fn successors(&self, u: NodeId) -> &[NodeId] {
+        &self[u as usize]
+    }
+}
+
+impl Digraph for Vec<Vec<NodeId>> {
+    fn num_nodes(&self) -> usize {
+        self.len()
+    }
+
+    fn successors(&self, u: NodeId) -> &[NodeId] {
+        &self[u as usize]
+    }
+}
+
+// A directed graph whose nodes have names, interned to dense IDs in the order they're first seen.
+// Adjacency is stored CSR-style: the successors of `u` are `targets[offsets[u]..offsets[u + 1]]`.
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    offsets: Vec<usize>,
+    targets: Vec<NodeId>,
+}
+
+impl Graph {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, u: NodeId) -> &str {
+        &self.names[u as usize]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + use<> {
+        0..self.len() as NodeId
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.nodes()
+            .flat_map(move |u| self.successors(u).iter().map(move |&v| (u, v)))
+    }
+
+    // Graphviz DOT for the graph, with the `highlight` nodes filled in and edges labelled by
+    // `edge_label` where it returns Some.
+    pub fn to_dot(
+        &self,
+        highlight: &[NodeId],
+        mut edge_label: impl FnMut(NodeId, NodeId) -> Option<String>,
+    ) -> String {
+        let mut output = String::from("digraph {\n");
+        for u in self.nodes() {
+            let style = if highlight.contains(&u) {
+                " [style=filled, fillcolor=gold]"
+            } else {
+                ""
+            };
+            output.push_str(&format!("    {}{};\n", dot_quote(self.name(u)), style));
+        }
+        for (u, v) in self.edges() {
+            let label = match edge_label(u, v) {
+                Some(label) => format!(" [label={}]", dot_quote(&label)),
+                None => String::new(),
+            };
+            output.push_str(&format!(
+                "    {} -> {}{};\n",
+                dot_quote(self.name(u)),
+                dot_quote(self.name(v)),
+                label
+            ));
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    // The same nodes, with the same IDs, and every edge flipped.
+    pub fn reversed(&self) -> Graph {
+        let mut builder = GraphBuilder {
+            names: self.names.clone(),
+            ids: self.ids.clone(),
+            edges: self.edges().map(|(u, v)| (v, u)).collect(),
+        };
+        builder.edges.sort();
+        builder.build()
+    }
+}
+
+impl Digraph for Graph {
+    fn num_nodes(&self) -> usize {
+        self.len()
+    }
+
+    fn successors(&self, u: NodeId) -> &[NodeId] {
+        &self.targets[self.offsets[u as usize]..self.offsets[u as usize + 1]]
+    }
+}
+
+// Collects named nodes and edges for a `Graph`. Successors keep the order their edges were added in.
+#[derive(Clone, Debug, Default)]
+pub struct GraphBuilder {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<(NodeId, NodeId)>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The ID of `name`, adding it as a new node if it hasn't been seen yet.
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(&u) = self.ids.get(name) {
+            return u;
+        }
+        let u = self.names.len() as NodeId;
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), u);
+        u
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str) {
+        let (u, v) = (self.node(from), self.node(to));
+        self.edges.push((u, v));
+    }
+
+    pub fn build(self) -> Graph {
+        let n = self.names.len();
+        let mut offsets = vec![0; n + 1];
+        for &(u, _) in &self.edges {
+            offsets[u as usize + 1] += 1;
+        }
+        for u in 0..n {
+            offsets[u + 1] += offsets[u];
+        }
+        let mut next = offsets.clone();
+        let mut targets = vec![0; self.edges.len()];
+        for (u, v) in self.edges {
+            targets[next[u as usize]] = v;
+            next[u as usize] += 1;
+        }
+        Graph {
+            names: self.names,
+            ids: self.ids,
+            offsets,
+            targets,
+        }
+    }
+}
+
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// A cycle found while sorting, as the nodes along it. The last node has an edge back to the first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub nodes: Vec<NodeId>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle: ")?;
+        for u in &self.nodes {
+            write!(f, "{} -> ", u)?;
+        }
+        match self.nodes.first() {
+            Some(u) => write!(f, "{}", u),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for Cycle {}
+
+// Topological order of the nodes where `keep` is true, ignoring edges to the others.
+fn topo_sort_within<G: Digraph + ?Sized>(g: &G, keep: &[bool]) -> Result<Vec<NodeId>, Cycle> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Mark {
+        New,
+        Active,
+        Done,
+    }
+    let n = g.num_nodes();
+    let mut mark = vec![Mark::New; n];
+    let mut postorder = Vec::with_capacity(n);
+    // Explicit DFS stack of (node, index of the next successor to look at)
+    let mut stack: Vec<(NodeId, usize)> = vec![];
+    for root in 0..n as NodeId {
+        if !keep[root as usize] || mark[root as usize] != Mark::New {
+            continue;
+        }
+        mark[root as usize] = Mark::Active;
+        stack.push((root, 0));
+        while let Some(&(u, next)) = stack.last() {
+            let succ = g.successors(u);
+            if next < succ.len() {
+                let v = succ[next];
+                stack.last_mut().unwrap().1 += 1;
+                if !keep[v as usize] {
+                    continue;
+                }
+                match mark[v as usize] {
+                    Mark::New => {
+                        mark[v as usize] = Mark::Active;
+                        stack.push((v, 0));
+                    }
+                    Mark::Active => {
+                        let start = stack.iter().position(|&(w, _)| w == v).unwrap();
+                        let nodes = stack[start..].iter().map(|&(w, _)| w).collect();
+                        return Err(Cycle { nodes });
+                    }
+                    Mark::Done => {}
+                }
+            } else {
+                mark[u as usize] = Mark::Done;
+                postorder.push(u);
+                stack.pop();
+            }
+        }
+    }
+    postorder.reverse();
+    Ok(postorder)
+}
+
+// Every node ordered so that edges only point forwards, or a cycle that makes that impossible.
+pub fn topo_sort<G: Digraph + ?Sized>(g: &G) -> Result<Vec<NodeId>, Cycle> {
+    topo_sort_within(g, &vec![true; g.num_nodes()])
+}
+
+pub fn reachable_from<G: Digraph + ?Sized>(g: &G, start: NodeId) -> Vec<bool> {
+    let mut seen = vec![false; g.num_nodes()];
+    seen[start as usize] = true;
+    let mut stack = vec![start];
+    while let Some(u) = stack.pop() {
+        for &v in g.successors(u) {
+            if !seen[v as usize] {
+                seen[v as usize] = true;
+                stack.push(v);
+            }
+        }
+    }
+    seen
+}
+
+// The graph with every edge flipped.
+pub fn reversed<G: Digraph + ?Sized>(g: &G) -> Vec<Vec<NodeId>> {
+    let mut output = vec![vec![]; g.num_nodes()];
+    for u in 0..g.num_nodes() as NodeId {
+        for &v in g.successors(u) {
+            output[v as usize].push(u);
+        }
+    }
+    output
+}
+
+// Number of paths from `start` to every node. Only cycles reachable from `start` are an error,
+// since they'd make some counts infinite.
+pub fn path_counts_from<G: Digraph + ?Sized>(g: &G, start: NodeId) -> Result<Vec<BigUint>, Cycle> {
+    let order = topo_sort_within(g, &reachable_from(g, start))?;
+    let mut counts = vec![BigUint::zero(); g.num_nodes()];
+    counts[start as usize] = BigUint::one();
+    for u in order {
+        if counts[u as usize].is_zero() {
+            continue;
+        }
+        let here = counts[u as usize].clone();
+        for &v in g.successors(u) {
+            counts[v as usize] += &here;
+        }
+    }
+    Ok(counts)
+}
+
+// Number of paths from `from` to `to`. Only a cycle that lies on some such path is an error.
+pub fn count_paths<G: Digraph + ?Sized>(g: &G, from: NodeId, to: NodeId) -> Result<BigUint, Cycle> {
+    let forward = reachable_from(g, from);
+    let backward = reachable_from(&reversed(g), to);
+    let keep: Vec<bool> = forward
+        .iter()
+        .zip(&backward)
+        .map(|(&f, &b)| f && b)
+        .collect();
+    if !keep[from as usize] {
+        return Ok(BigUint::zero());
+    }
+    let order = topo_sort_within(g, &keep)?;
+    let mut counts = vec![BigUint::zero(); g.num_nodes()];
+    counts[from as usize] = BigUint::one();
+    for u in order {
+        let here = counts[u as usize].clone();
+        for &v in g.successors(u) {
+            if keep[v as usize] {
+                counts[v as usize] += &here;
+            }
+        }
+    }
+    Ok(std::mem::take(&mut counts[to as usize]))
+}
+
+// Number of paths from `from` to `to` that pass through every node in `waypoints`, in whatever
+// order. Without cycles a path meets the waypoints in exactly one order, so this sums the product
+// of segment counts over every ordering.
+pub fn count_paths_via<G: Digraph + ?Sized>(
+    g: &G,
+    from: NodeId,
+    to: NodeId,
+    waypoints: &[NodeId],
+) -> Result<BigUint, Cycle> {
+    let mut total = BigUint::zero();
+    let mut order: Vec<NodeId> = waypoints.to_vec();
+    order.sort();
+    order.dedup();
+    loop {
+        let mut product = BigUint::one();
+        let mut prev = from;
+        for &w in order.iter().chain([to].iter()) {
+            product = &product * &count_paths(g, prev, w)?;
+            if product.is_zero() {
+                break;
+            }
+            prev = w;
+        }
+        total += &product;
+        if !next_permutation(&mut order) {
+            break;
+        }
+    }
+    Ok(total)
+}
+
+// Steps `v` to the next lexicographic permutation, returning false once it wraps back to sorted.
+fn next_permutation<T: Ord>(v: &mut [T]) -> bool {
+    let Some(i) = (1..v.len()).rev().find(|&i| v[i - 1] < v[i]) else {
+        v.reverse();
+        return false;
+    };
+    let j = (i..v.len()).rev().find(|&j| v[i - 1] < v[j]).unwrap();
+    v.swap(i - 1, j);
+    v[i..].reverse();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3, 0 -> 2 -> 3, 1 -> 2, 3 -> 4
+    fn diamond() -> Vec<Vec<NodeId>> {
+        vec![vec![1, 2], vec![3, 2], vec![3], vec![4], vec![]]
+    }
+
+    #[test]
+    fn sorts_and_finds_cycles() {
+        let g = diamond();
+        let order = topo_sort(&g).unwrap();
+        let pos = |u: NodeId| order.iter().position(|&w| w == u).unwrap();
+        for u in 0..5 {
+            for &v in g.successors(u) {
+                assert!(pos(u) < pos(v));
+            }
+        }
+
+        let mut cyclic = diamond();
+        cyclic[4].push(1);
+        let cycle = topo_sort(&cyclic).unwrap_err();
+        assert_eq!(cycle.nodes, [1, 3, 4]);
+        assert_eq!(cycle.to_string(), "cycle: 1 -> 3 -> 4 -> 1");
+    }
+
+    #[test]
+    fn counts_paths() {
+        let g = diamond();
+        assert_eq!(count_paths(&g, 0, 4).unwrap(), BigUint::from(3));
+        assert_eq!(count_paths(&g, 4, 0).unwrap(), BigUint::zero());
+        assert_eq!(path_counts_from(&g, 1).unwrap()[3], BigUint::from(2));
+        assert_eq!(count_paths_via(&g, 0, 4, &[2]).unwrap(), BigUint::from(2));
+        assert_eq!(count_paths_via(&g, 0, 4, &[2, 1]).unwrap(), BigUint::one());
+        assert_eq!(count_paths_via(&g, 0, 4, &[]).unwrap(), BigUint::from(3));
+
+        // A cycle off to the side doesn't matter, one on the way does
+        let mut g = diamond();
+        g.push(vec![5]);
+        assert!(count_paths(&g, 0, 4).is_ok());
+        g[3].push(1);
+        assert!(count_paths(&g, 0, 4).is_err());
+    }
+
+    #[test]
+    fn named_graph() {
+        let mut builder = GraphBuilder::new();
+        builder.edge("you", "bbb");
+        builder.edge("you", "ccc");
+        builder.edge("bbb", "out");
+        builder.edge("ccc", "out");
+        builder.node("lonely");
+        let g = builder.build();
+        assert_eq!(g.len(), 5);
+        let [you, bbb, ccc, out] = ["you", "bbb", "ccc", "out"].map(|n| g.id(n).unwrap());
+        assert_eq!(g.successors(you), [bbb, ccc]);
+        assert_eq!(g.successors(out), []);
+        assert_eq!(g.name(ccc), "ccc");
+        assert_eq!(g.id("nope"), None);
+        assert_eq!(count_paths(&g, you, out).unwrap(), BigUint::from(2));
+
+        let r = g.reversed();
+        assert_eq!(r.id("out"), Some(out));
+        assert_eq!(r.successors(out), [bbb, ccc]);
+        assert_eq!(r.edges().count(), 4);
+    }
+
+    #[test]
+    fn dot() {
+        let mut builder = GraphBuilder::new();
+        builder.edge("a", "b");
+        builder.edge("b", "say \"hi\"");
+        let g = builder.build();
+        let dot = g.to_dot(&[0], |u, _| (u == 0).then(|| "1".to_string()));
+        let expected = r#"digraph {
+    "a" [style=filled, fillcolor=gold];
+    "b";
+    "say \"hi\"";
+    "a" -> "b" [label="1"];
+    "b" -> "say \"hi\"";
+}
+"#;
+        assert_eq!(dot, expected);
+    }
+}
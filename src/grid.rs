@@ -0,0 +1,265 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+use crate::num::Integer;
+use crate::parse::lines;
+use crate::point::Point2;
+
+// A rectangular map stored row by row. Cells are addressed as `(x, y)` with `(0, 0)` in the top
+// left and y growing downwards, matching how the puzzle inputs are laid out.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Parses a char map, one row per line. `cell` turns each char into a cell or explains what it
+    // expected instead, and every row has to be as wide as the first.
+    pub fn parse<F, E>(s: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for line in lines(s) {
+            let start = cells.len();
+            for (i, c) in line.text.char_indices() {
+                cells.push(cell(c).map_err(|e| line.char_error(i, e))?);
+            }
+            let row_width = cells.len() - start;
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(line.error(line.text, format!("expected a row {} wide", width)));
+            }
+            height += 1;
+        }
+        if height == 0 || width == 0 {
+            return Err(ParseError::end_of_input(s, "expected a grid"));
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.in_bounds(x, y) {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.in_bounds(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    // Positions of the up, right, down and left neighbours that are on the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        Point2::new(x, y)
+            .neighbors4()
+            .filter(move |p| p.x < width && p.y < height)
+            .map(|p| p.into())
+    }
+
+    // As `neighbors4`, plus the diagonals, clockwise from straight up.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        Point2::new(x, y)
+            .neighbors8()
+            .filter(move |p| p.x < width && p.y < height)
+            .map(|p| p.into())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Every `(x, y)`, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i % width, i / width))
+    }
+
+    // Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Integer> Grid<T> {
+    pub fn prefix_sums(&self) -> PrefixSums<T> {
+        PrefixSums::new(self)
+    }
+}
+
+// Summed-area table of a grid: the total of any rectangle of cells in constant time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrefixSums<T> {
+    // `sums[(x, y)]` is the total of every cell above and to the left of `(x, y)`, exclusive
+    sums: Grid<T>,
+}
+
+impl<T: Integer> PrefixSums<T> {
+    pub fn new(grid: &Grid<T>) -> Self {
+        let mut sums = Grid::new(grid.width + 1, grid.height + 1, T::ZERO);
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                sums[(x + 1, y + 1)] =
+                    grid[(x, y)] + sums[(x, y + 1)] + sums[(x + 1, y)] - sums[(x, y)];
+            }
+        }
+        Self { sums }
+    }
+
+    // Total of the cells from `(x0, y0)` to `(x1, y1)` inclusive.
+    pub fn sum(&self, (x0, y0): (usize, usize), (x1, y1): (usize, usize)) -> T {
+        let s = &self.sums;
+        s[(x1 + 1, y1 + 1)] + s[(x0, y0)] - s[(x0, y1 + 1)] - s[(x1 + 1, y0)]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+// One line per row, each cell printed with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("ab.\n.cd\n", Ok::<_, &str>).unwrap()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let g = sample();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.get(1, 0), Some(&'b'));
+        assert_eq!(g.get(3, 0), None);
+        assert_eq!(g[(2, 1)], 'd');
+        assert_eq!(g.row(1), ['.', 'c', 'd']);
+        assert_eq!(g.column(1).collect::<String>(), "bc");
+        assert_eq!(g.columns().count(), 3);
+        assert_eq!(g.to_string(), "ab.\n.cd\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Grid::parse("..\n.x", |c| match c {
+            '.' => Ok(()),
+            _ => Err("expected `.`"),
+        })
+        .unwrap_err();
+        assert_eq!((e.line, e.column, e.token.as_str()), (2, 2, "x"));
+
+        let e = Grid::parse("...\n..", Ok::<_, &str>).unwrap_err();
+        assert_eq!(e.message, "expected a row 3 wide");
+        assert!(Grid::parse("\n", Ok::<_, &str>).is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let g = Grid::new(3, 3, 0);
+        assert_eq!(g.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(g.neighbors4(1, 1).count(), 4);
+        assert_eq!(g.neighbors8(1, 1).count(), 8);
+        assert_eq!(
+            g.neighbors8(2, 2).collect::<Vec<_>>(),
+            [(2, 1), (1, 2), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn prefix_sums() {
+        let g = Grid::parse("123\n456\n789\n", |c| {
+            c.to_digit(10).ok_or("expected a digit")
+        })
+        .unwrap();
+        let sums = g.prefix_sums();
+        assert_eq!(sums.sum((0, 0), (2, 2)), 45);
+        assert_eq!(sums.sum((1, 1), (2, 2)), 5 + 6 + 8 + 9);
+        assert_eq!(sums.sum((2, 0), (2, 0)), 3);
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt;
+
+use crate::linalg::{Field, Overflow, pivot};
+use crate::rational::Rational;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IlpError {
+    Infeasible,
+    // The LP relaxation has no minimum
+    Unbounded,
+    // Some intermediate value didn't fit
+    Overflow,
+}
+
+impl fmt::Display for IlpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IlpError::Infeasible => write!(f, "no integer solution"),
+            IlpError::Unbounded => write!(f, "objective is unbounded"),
+            IlpError::Overflow => write!(f, "arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for IlpError {}
+
+impl From<Overflow> for IlpError {
+    fn from(_: Overflow) -> Self {
+        IlpError::Overflow
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Optimum {
+    pub value: i64,
+    pub x: Vec<i64>,
+}
+
+// Dense simplex tableau for `min c·y` subject to `A y = b`, `y >= 0`. The first rows are
+// `[A | b]`, and the last holds the reduced costs and the objective value negated. `basis[i]` is
+// the column that's basic in row `i`.
+struct Tableau<F> {
+    m: Vec<Vec<F>>,
+    basis: Vec<usize>,
+}
+
+impl<F: Field> Tableau<F> {
+    fn num_rows(&self) -> usize {
+        self.basis.len()
+    }
+
+    fn rhs(&self, i: usize) -> F {
+        *self.m[i].last().unwrap()
+    }
+
+    fn pivot(&mut self, r: usize, col: usize) -> Result<(), Overflow> {
+        pivot(&mut self.m, r, col)?;
+        self.basis[r] = col;
+        Ok(())
+    }
+
+    // Pivots until no column below `ncols` has a negative reduced cost. Bland's rule (lowest
+    // index enters, ties in the ratio test go to the lowest basic index) rules out cycling.
+    fn optimize(&mut self, ncols: usize) -> Result<(), IlpError> {
+        let cost = self.num_rows();
+        while let Some(col) = (0..ncols).find(|&j| self.m[cost][j] < F::ZERO) {
+            let mut best: Option<(F, usize)> = None;
+            for i in 0..self.num_rows() {
+                let a = self.m[i][col];
+                if a > F::ZERO {
+                    let ratio = self.rhs(i).checked_div(a).ok_or(Overflow)?;
+                    let better = match best {
+                        None => true,
+                        Some((r, b)) => ratio < r || (ratio == r && self.basis[i] < self.basis[b]),
+                    };
+                    if better {
+                        best = Some((ratio, i));
+                    }
+                }
+            }
+            let (_, r) = best.ok_or(IlpError::Unbounded)?;
+            self.pivot(r, col)?;
+        }
+        Ok(())
+    }
+}
+
+// Two-phase simplex for `min c·x` subject to `a x = b` and `lower <= x <= upper`, with integer
+// data. Returns the optimum value and a vertex that attains it.
+fn solve_lp<F: Field>(
+    a: &[Vec<i64>],
+    b: &[i64],
+    c: &[i64],
+    lower: &[i64],
+    upper: &[Option<i64>],
+) -> Result<(F, Vec<F>), IlpError> {
+    let n = c.len();
+    // Shift to `y = x - lower >= 0`, and give each upper bound a slack column
+    let bounded: Vec<usize> = (0..n).filter(|&j| upper[j].is_some()).collect();
+    let nvars = n + bounded.len();
+    let mut rows: Vec<(Vec<i64>, i64)> = vec![];
+    for (row, &bi) in a.iter().zip(b) {
+        let mut r = row.clone();
+        r.resize(nvars, 0);
+        rows.push((r, bi.checked_sub(dot(row, lower)?).ok_or(Overflow)?));
+    }
+    for (k, &j) in bounded.iter().enumerate() {
+        let mut r = vec![0; nvars];
+        r[j] = 1;
+        r[n + k] = 1;
+        let range = upper[j].unwrap().checked_sub(lower[j]).ok_or(Overflow)?;
+        rows.push((r, range));
+    }
+
+    // Phase 1: an artificial column per row, minimising their sum from the all-artificial basis.
+    // Rows are negated as needed so that basis starts out feasible.
+    let k = rows.len();
+    let width = nvars + k + 1;
+    let mut m = vec![vec![F::ZERO; width]; k + 1];
+    for (i, (r, rhs)) in rows.iter().enumerate() {
+        let sign = if *rhs < 0 { -F::ONE } else { F::ONE };
+        for (cell, &v) in m[i].iter_mut().zip(r) {
+            *cell = F::from_i64(v).checked_mul(sign).ok_or(Overflow)?;
+        }
+        m[i][nvars + i] = F::ONE;
+        m[i][width - 1] = F::from_i64(*rhs).checked_mul(sign).ok_or(Overflow)?;
+    }
+    let (constraints, costs) = m.split_at_mut(k);
+    for row in constraints.iter() {
+        for j in (0..nvars).chain([width - 1]) {
+            costs[0][j] = costs[0][j].checked_sub(row[j]).ok_or(Overflow)?;
+        }
+    }
+    let mut t = Tableau {
+        m,
+        basis: (nvars..nvars + k).collect(),
+    };
+    t.optimize(nvars)?;
+    if !t.m[t.num_rows()][width - 1].is_zero() {
+        return Err(IlpError::Infeasible);
+    }
+
+    // Swap any artificials still basic (at zero) for real columns, dropping redundant rows
+    let mut i = 0;
+    while i < t.num_rows() {
+        if t.basis[i] >= nvars {
+            match (0..nvars).find(|&j| !t.m[i][j].is_zero()) {
+                Some(j) => t.pivot(i, j)?,
+                None => {
+                    t.m.remove(i);
+                    t.basis.remove(i);
+                    continue;
+                }
+            }
+        }
+        i += 1;
+    }
+
+    // Phase 2: the real objective, priced out against the current basis
+    let cost = t.num_rows();
+    t.m[cost] = vec![F::ZERO; width];
+    for (cell, &v) in t.m[cost].iter_mut().zip(c) {
+        *cell = F::from_i64(v);
+    }
+    t.m[cost][width - 1] = F::from_i64(dot(c, lower)?).checked_neg().ok_or(Overflow)?;
+    for i in 0..t.num_rows() {
+        let cb = t.m[cost][t.basis[i]];
+        if !cb.is_zero() {
+            for j in 0..width {
+                t.m[cost][j] = cb
+                    .checked_mul(t.m[i][j])
+                    .and_then(|v| t.m[cost][j].checked_sub(v))
+                    .ok_or(Overflow)?;
+            }
+        }
+    }
+    t.optimize(nvars)?;
+
+    let mut x: Vec<F> = lower.iter().map(|&l| F::from_i64(l)).collect();
+    for (i, &j) in t.basis.iter().enumerate() {
+        if j < n {
+            x[j] = x[j].checked_add(t.rhs(i)).ok_or(Overflow)?;
+        }
+    }
+    Ok((t.m[cost][width - 1].checked_neg().ok_or(Overflow)?, x))
+}
+
+fn dot(a: &[i64], b: &[i64]) -> Result<i64, Overflow> {
+    a.iter().zip(b).try_fold(0_i64, |sum, (&x, &y)| {
+        x.checked_mul(y)
+            .and_then(|p| sum.checked_add(p))
+            .ok_or(Overflow)
+    })
+}
+
+// Integer linear programming: `min c·x` subject to `a x = b`, `x >= 0` integer. Branch and bound
+// over exact rational LP relaxations, splitting on the first fractional variable.
+pub fn minimize(a: &[Vec<i64>], b: &[i64], c: &[i64]) -> Result<Optimum, IlpError> {
+    let n = c.len();
+    let mut best: Option<Optimum> = None;
+    let mut stack = vec![(vec![0; n], vec![None; n])];
+    while let Some((lower, upper)) = stack.pop() {
+        let (value, x) = match solve_lp::<Rational>(a, b, c, &lower, &upper) {
+            Ok(solution) => solution,
+            Err(IlpError::Infeasible) => continue,
+            Err(e) => return Err(e),
+        };
+        // The objective is integral on integer points, so round the bound up
+        if best
+            .as_ref()
+            .is_some_and(|o| value.ceil() >= o.value as i128)
+        {
+            continue;
+        }
+        match x.iter().position(|v| !v.is_integer()) {
+            Some(j) => {
+                let mut down = upper.clone();
+                down[j] = Some(to_i64(x[j].floor())?);
+                let mut up = lower.clone();
+                up[j] = to_i64(x[j].ceil())?;
+                stack.push((lower, down));
+                stack.push((up, upper));
+            }
+            None => {
+                best = Some(Optimum {
+                    value: to_i64(value.numer())?,
+                    x: x.iter()
+                        .map(|v| to_i64(v.numer()))
+                        .collect::<Result<_, _>>()?,
+                })
+            }
+        }
+    }
+    best.ok_or(IlpError::Infeasible)
+}
+
+fn to_i64(n: i128) -> Result<i64, IlpError> {
+    i64::try_from(n).map_err(|_| IlpError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn button_presses() {
+        // Buttons (3) (1,3) (2) (2,3) (0,2) (0,1) reaching joltages {3,5,4,7}
+        let buttons: [&[usize]; 6] = [&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]];
+        let mut a = vec![vec![0; 6]; 4];
+        for (j, lights) in buttons.iter().enumerate() {
+            for &i in *lights {
+                a[i][j] = 1;
+            }
+        }
+        let optimum = minimize(&a, &[3, 5, 4, 7], &[1; 6]).unwrap();
+        assert_eq!(optimum.value, 10);
+        for i in 0..4 {
+            let total: i64 = (0..6).map(|j| a[i][j] * optimum.x[j]).sum();
+            assert_eq!(total, [3, 5, 4, 7][i]);
+        }
+    }
+
+    #[test]
+    fn needs_branching() {
+        // The relaxation's best is x = (1.5, 0, 0), which rounds to nothing feasible
+        let a = vec![vec![2, 1, 1]];
+        assert_eq!(
+            minimize(&a, &[3], &[1, 1, 3]),
+            Ok(Optimum {
+                value: 2,
+                x: vec![1, 1, 0]
+            })
+        );
+        assert_eq!(
+            minimize(&[vec![2, 2]], &[3], &[1, 1]),
+            Err(IlpError::Infeasible)
+        );
+        assert_eq!(
+            minimize(&[vec![1, -1]], &[0], &[0, -1]),
+            Err(IlpError::Unbounded)
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        // Coefficients near i64::MAX give fractions whose cross products pass i128
+        let m = i64::MAX;
+        let a = vec![vec![m, m / 3], vec![m / 5, m / 7]];
+        assert_eq!(
+            minimize(&a, &[m / 11, m / 13], &[1, 1]),
+            Err(IlpError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// Overrides the directory the puzzle inputs and answers are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// `$AOC_INPUT_DIR`, else `inputs` in the working directory, else the crate's own `inputs`, so the
+// binaries work from anywhere on the machine they were built on.
+pub fn input_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    let local = PathBuf::from("inputs");
+    if local.is_dir() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+    }
+}
+
+// Where an input comes from. `-` on the command line means stdin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    // Name to attach to parse errors.
+    pub fn path(&self) -> &Path {
+        match self {
+            Source::Stdin => Path::new("<stdin>"),
+            Source::File(path) => path,
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Stdin => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s).map(|_| s)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path().display())
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Source::File(path) if self.error.kind() == io::ErrorKind::NotFound => {
+                let name = path.file_name().unwrap_or(path.as_os_str());
+                write!(
+                    f,
+                    "missing input file `{}` (looked for {}; set {} or pass a path)",
+                    name.to_string_lossy(),
+                    path.display(),
+                    INPUT_DIR_VAR
+                )
+            }
+            source => write!(f, "{}: {}", source, self.error),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_from_args() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("inputs/day07.txt"),
+            Source::File(PathBuf::from("inputs/day07.txt"))
+        );
+        assert_eq!(Source::Stdin.to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn missing_file_names_expected_file() {
+        let source = Source::File(PathBuf::from("nowhere/day07.txt"));
+        let e = source.read().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "missing input file `day07.txt` (looked for nowhere/day07.txt; set AOC_INPUT_DIR or pass a path)"
+        );
+    }
+}
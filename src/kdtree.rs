@@ -0,0 +1,242 @@
+use std::collections::BinaryHeap;
+
+use crate::pairs::Edge;
+use crate::point::Coords;
+
+// Static k-d tree over a slice of points. The tree is implicit: `order` holds point indices
+// arranged so that each subrange's middle element splits the rest on the axis for its depth.
+// All distances are squared Euclidean, and results refer to points by their index in the slice.
+pub struct KdTree<'a, P: Coords> {
+    points: &'a [P],
+    order: Vec<usize>,
+}
+
+impl<'a, P: Coords> KdTree<'a, P> {
+    pub fn new(points: &'a [P]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+        Self { points, order }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &'a [P] {
+        self.points
+    }
+
+    // The `k` points closest to `query` as `(index, dist)`, closest first and ties broken by index.
+    pub fn nearest(&self, query: &P, k: usize) -> Vec<(usize, i64)> {
+        let mut best = BinaryHeap::new();
+        if k > 0 {
+            self.nearest_in(query, k, 0, self.order.len(), 0, &mut best);
+        }
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|(d, i)| (i, d))
+            .collect()
+    }
+
+    fn nearest_in(
+        &self,
+        query: &P,
+        k: usize,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        best: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let i = self.order[mid];
+        let candidate = (query.dist_squared_to(&self.points[i]), i);
+        if best.len() < k {
+            best.push(candidate);
+        } else if best.peek().is_some_and(|&worst| candidate < worst) {
+            best.pop();
+            best.push(candidate);
+        }
+
+        let axis = depth % P::DIM;
+        let diff = query.coord(axis) - self.points[i].coord(axis);
+        let (near, far) = if diff < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.nearest_in(query, k, near.0, near.1, depth + 1, best);
+        // Points on the far side are at least `diff` away along this axis
+        if best.len() < k
+            || best
+                .peek()
+                .is_some_and(|&(worst, _)| diff.saturating_mul(diff) <= worst)
+        {
+            self.nearest_in(query, k, far.0, far.1, depth + 1, best);
+        }
+    }
+
+    // Indices of every point within `max_dist` (squared, inclusive) of `query`, in index order.
+    pub fn within(&self, query: &P, max_dist: i64) -> Vec<usize> {
+        let mut output = vec![];
+        self.within_in(query, max_dist, 0, self.order.len(), 0, &mut output);
+        output.sort();
+        output
+    }
+
+    fn within_in(
+        &self,
+        query: &P,
+        max_dist: i64,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        output: &mut Vec<usize>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let i = self.order[mid];
+        if query.dist_squared_to(&self.points[i]) <= max_dist {
+            output.push(i);
+        }
+        let axis = depth % P::DIM;
+        let diff = query.coord(axis) - self.points[i].coord(axis);
+        if diff <= 0 || diff.saturating_mul(diff) <= max_dist {
+            self.within_in(query, max_dist, lo, mid, depth + 1, output);
+        }
+        if diff >= 0 || diff.saturating_mul(diff) <= max_dist {
+            self.within_in(query, max_dist, mid + 1, hi, depth + 1, output);
+        }
+    }
+
+    // Every pair of points within `max_dist` (squared, inclusive) of each other, closest first
+    // and ties broken by index.
+    pub fn pairs_within(&self, max_dist: i64) -> Vec<Edge> {
+        let mut edges = vec![];
+        for a in 0..self.points.len() {
+            for b in self.within(&self.points[a], max_dist) {
+                if b > a {
+                    let dist = self.points[a].dist_squared_to(&self.points[b]);
+                    edges.push(Edge { dist, a, b });
+                }
+            }
+        }
+        edges.sort();
+        edges
+    }
+
+    // The closest pair of distinct points, None if there are fewer than two.
+    pub fn closest_pair(&self) -> Option<Edge> {
+        (0..self.points.len())
+            .filter_map(|a| {
+                // A duplicate of `a` with a lower index sorts ahead of `a` itself, so skip `a` by
+                // index rather than by position
+                let (b, dist) = self
+                    .nearest(&self.points[a], 2)
+                    .into_iter()
+                    .find(|&(b, _)| b != a)?;
+                Some(Edge {
+                    dist,
+                    a: a.min(b),
+                    b: a.max(b),
+                })
+            })
+            .min()
+    }
+}
+
+fn build<P: Coords>(points: &[P], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let axis = depth % P::DIM;
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by_key(mid, |&i| (points[i].coord(axis), i));
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A deterministic scatter of 3D points with a few duplicates.
+    fn scatter() -> Vec<[i64; 3]> {
+        let mut v = vec![];
+        let mut x: i64 = 12345;
+        for _ in 0..60 {
+            x = (x * 1103515245 + 12345) % 2147483648;
+            v.push([x % 100, (x / 100) % 100, (x / 10000) % 100]);
+        }
+        v.push(v[3]);
+        v
+    }
+
+    #[test]
+    fn nearest_and_within_match_brute_force() {
+        let points = scatter();
+        let tree = KdTree::new(&points);
+        for q in [[0, 0, 0], [50, 50, 50], points[7], [99, 3, 42]] {
+            let mut all: Vec<(i64, usize)> = (0..points.len())
+                .map(|i| (q.dist_squared_to(&points[i]), i))
+                .collect();
+            all.sort();
+            let expected: Vec<(usize, i64)> = all.iter().take(5).map(|&(d, i)| (i, d)).collect();
+            assert_eq!(tree.nearest(&q, 5), expected);
+
+            let mut inside: Vec<usize> = all
+                .iter()
+                .filter(|&&(d, _)| d <= 400)
+                .map(|&(_, i)| i)
+                .collect();
+            inside.sort();
+            assert_eq!(tree.within(&q, 400), inside);
+        }
+    }
+
+    #[test]
+    fn pairs() {
+        let points = scatter();
+        let tree = KdTree::new(&points);
+        let edges = tree.pairs_within(150);
+        let mut expected = vec![];
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                let dist = points[a].dist_squared_to(&points[b]);
+                if dist <= 150 {
+                    expected.push(Edge { dist, a, b });
+                }
+            }
+        }
+        expected.sort();
+        assert_eq!(edges, expected);
+        assert_eq!(
+            tree.closest_pair(),
+            Some(Edge {
+                dist: 0,
+                a: 3,
+                b: 60
+            })
+        );
+
+        let same = KdTree::new(&[[4, 4, 4]; 3]);
+        assert_eq!(
+            same.closest_pair(),
+            Some(Edge {
+                dist: 0,
+                a: 0,
+                b: 1
+            })
+        );
+        assert_eq!(KdTree::new(&[[4, 4, 4]]).closest_pair(), None);
+    }
+}
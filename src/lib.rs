@@ -1,65 +1,41 @@
-use std::cmp::{max, min};
+pub mod answers;
+pub mod bench;
+pub mod biguint;
+pub mod compress;
+pub mod days;
+pub mod disjoint_set;
+pub mod gf2;
+pub mod graph;
+pub mod grid;
+pub mod ilp;
+pub mod input;
+pub mod kdtree;
+pub mod linalg;
+pub mod num;
+pub mod packing;
+pub mod pairs;
+pub mod parse;
+pub mod point;
+pub mod polygon;
+pub mod polyomino;
+pub mod range;
+pub mod rational;
+pub mod rect;
+pub mod runner;
+pub mod search;
+pub mod solution;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Range {
-    start: usize,
-    end: usize,
-}
-
-impl Range {
-    pub fn new(start: usize, end: usize) -> Self {
-        Self {
-            start: min(start, end),
-            end: max(start, end),
-        }
-    }
-
-    pub fn intersect(&self, other: Range) -> Option<Range> {
-        if self.start > other.end {
-            None
-        } else if other.start > self.end {
-            None
-        } else {
-            Some(Range::new(
-                max(self.start, other.start),
-                min(self.end, other.end),
-            ))
-        }
-    }
-
-    pub fn union(&self, other: Range) -> Vec<Range> {
-        if self.start > other.end {
-            vec![other, *self]
-        } else if other.start > self.end {
-            vec![*self, other]
-        } else {
-            vec![Range::new(
-                min(self.start, other.start),
-                max(self.end, other.end),
-            )]
-        }
-    }
-
-    pub fn len(&self) -> usize {
-        self.end - self.start + 1
-    }
-}
-
-pub fn union(ranges: &[Range]) -> Vec<Range> {
-    let mut output = vec![];
-    for r in ranges {
-        let mut new = *r;
-        let mut remove = vec![];
-        for (i, o) in output.iter().enumerate() {
-            if new.intersect(*o).is_some() {
-                new = new.union(*o)[0];
-                remove.push(i);
-            }
-        }
-        for rem in remove.iter().rev() {
-            output.remove(*rem);
-        }
-        output.push(new);
-    }
-    output
-}
+pub use biguint::BigUint;
+pub use disjoint_set::DisjointSet;
+pub use graph::{Digraph, Graph, GraphBuilder, NodeId};
+pub use grid::{Grid, PrefixSums};
+pub use kdtree::KdTree;
+pub use num::Integer;
+pub use parse::ParseError;
+pub use point::{Coords, Dir4, Dir8, Point2, Point3};
+pub use polygon::OrthoPolygon;
+pub use polyomino::Polyomino;
+pub use range::{Range, RangeSet, union};
+pub use rational::Rational;
+pub use rect::Rect;
+pub use solution::{NoAnswer, Part, Solution};
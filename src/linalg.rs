@@ -0,0 +1,236 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::rational::Rational;
+
+// The arithmetic Gaussian elimination and the simplex method need. `Rational` gives exact
+// answers; `f64` is there for quick experiments and needs its results rounded by hand.
+pub trait Field:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_i64(n: i64) -> Self;
+
+    // None when the result doesn't fit, or for division by zero.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+
+    fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+}
+
+// An intermediate result didn't fit the field's number type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+impl Field for Rational {
+    const ZERO: Self = Rational::ZERO;
+    const ONE: Self = Rational::ONE;
+
+    fn from_i64(n: i64) -> Self {
+        Rational::from(n)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Rational::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Rational::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Rational::checked_mul(self, rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        Rational::checked_div(self, rhs)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        Rational::checked_neg(self)
+    }
+}
+
+// Floats overflow to infinity rather than failing, so that's what counts as overflow.
+fn finite(x: f64) -> Option<f64> {
+    Some(x).filter(|x| x.is_finite())
+}
+
+impl Field for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_i64(n: i64) -> Self {
+        n as f64
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        finite(self + rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        finite(self - rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        finite(self * rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        finite(self / rhs)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+// Scales row `r` so `m[r][c]` is one, then clears column `c` from every other row. On overflow
+// `m` is left part way through.
+pub fn pivot<F: Field>(m: &mut [Vec<F>], r: usize, c: usize) -> Result<(), Overflow> {
+    let p = m[r][c];
+    if p != F::ONE {
+        for x in &mut m[r] {
+            *x = x.checked_div(p).ok_or(Overflow)?;
+        }
+    }
+    let row = m[r].clone();
+    for (i, other) in m.iter_mut().enumerate() {
+        let a = other[c];
+        if i != r && !a.is_zero() {
+            for (x, &y) in other.iter_mut().zip(&row) {
+                *x = a
+                    .checked_mul(y)
+                    .and_then(|ay| x.checked_sub(ay))
+                    .ok_or(Overflow)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Puts `m` into reduced row echelon form, returning the pivot column of each nonzero row. Those
+// rows come first, in pivot order, and any zero rows are left at the bottom.
+pub fn row_reduce<F: Field>(m: &mut [Vec<F>]) -> Result<Vec<usize>, Overflow> {
+    let ncols = m.first().map_or(0, |row| row.len());
+    let mut pivots = vec![];
+    for c in 0..ncols {
+        let r = pivots.len();
+        if r == m.len() {
+            break;
+        }
+        if let Some(found) = (r..m.len()).find(|&i| !m[i][c].is_zero()) {
+            m.swap(r, found);
+            pivot(m, r, c)?;
+            pivots.push(c);
+        }
+    }
+    Ok(pivots)
+}
+
+// Solves the augmented system `[A | b]`, giving any free variables the value zero. None if the
+// system is inconsistent.
+pub fn solve<F: Field>(mut augmented: Vec<Vec<F>>) -> Result<Option<Vec<F>>, Overflow> {
+    let Some(n) = augmented.first().map(|row| row.len() - 1) else {
+        return Ok(None);
+    };
+    let pivots = row_reduce(&mut augmented)?;
+    if pivots.last() == Some(&n) {
+        return Ok(None);
+    }
+    let mut x = vec![F::ZERO; n];
+    for (r, &c) in pivots.iter().enumerate() {
+        x[c] = augmented[r][n];
+    }
+    Ok(Some(x))
+}
+
+// The normal equations `[AᵀA | Aᵀb]` of `[A | b]`, whose solutions are the least-squares
+// solutions of the original system.
+pub fn normal_equations<F: Field>(augmented: &[Vec<F>]) -> Result<Vec<Vec<F>>, Overflow> {
+    let n = augmented.first().map_or(0, |row| row.len() - 1);
+    let mut output = vec![vec![F::ZERO; n + 1]; n];
+    for r in 0..n {
+        for c in 0..=n {
+            for row in augmented {
+                output[r][c] = row[r]
+                    .checked_mul(row[c])
+                    .and_then(|p| output[r][c].checked_add(p))
+                    .ok_or(Overflow)?;
+            }
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(m: &[&[i64]]) -> Vec<Vec<Rational>> {
+        m.iter()
+            .map(|row| row.iter().map(|&v| Rational::from(v)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn exact_solutions() {
+        // x + y = 3, x - y = 1/2 scaled up: 2x - 2y = 1
+        let x = solve(rationals(&[&[1, 1, 3], &[2, -2, 1]]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(x, [Rational::new(7, 4), Rational::new(5, 4)]);
+
+        // Dependent rows leave a zero row and a free variable
+        let mut m = rationals(&[&[1, 1, 2], &[2, 2, 4], &[0, 1, 1]]);
+        assert_eq!(row_reduce(&mut m).unwrap(), [0, 1]);
+        assert_eq!(m, rationals(&[&[1, 0, 1], &[0, 1, 1], &[0, 0, 0]]));
+
+        assert_eq!(solve(rationals(&[&[1, 1, 1], &[1, 1, 2]])), Ok(None));
+
+        // Clearing x from the second row needs 2^64 times 2^63, past what i128 holds
+        let mut m = vec![
+            vec![
+                Rational::ONE,
+                Rational::new(1 << 62, 1),
+                Rational::new(1 << 63, 1),
+            ],
+            vec![Rational::new(1 << 64, 1), Rational::ONE, Rational::ONE],
+        ];
+        assert_eq!(row_reduce(&mut m), Err(Overflow));
+    }
+
+    #[test]
+    fn least_squares() {
+        // The best fit of y = a + bx through (0, 0), (1, 1), (2, 1)
+        let points = rationals(&[&[1, 0, 0], &[1, 1, 1], &[1, 2, 1]]);
+        let fit = solve(normal_equations(&points).unwrap()).unwrap().unwrap();
+        assert_eq!(fit, [Rational::new(1, 6), Rational::new(1, 2)]);
+        let fit = solve(vec![vec![3.0, 3.0, 2.0], vec![3.0, 5.0, 3.0]])
+            .unwrap()
+            .unwrap();
+        assert!((fit[0] - 1.0 / 6.0).abs() < 1e-12 && (fit[1] - 0.5).abs() < 1e-12);
+    }
+}
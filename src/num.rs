@@ -0,0 +1,52 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+// Primitive integer types, so ranges and coordinates can be written once for i64/u64/usize/...
+pub trait Integer:
+    Copy + Ord + Hash + Debug + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
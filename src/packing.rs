@@ -0,0 +1,317 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::polyomino::Polyomino;
+
+// One piece laid on the board: which piece it is and the board cells it covers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placement {
+    pub piece: usize,
+    pub cells: Vec<(usize, usize)>,
+}
+
+// Why no packing exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Infeasible {
+    // The pieces cover more cells than the board has
+    Area { needed: usize, available: usize },
+    // This piece doesn't fit on the board in any orientation
+    Oversized(usize),
+    // Every arrangement was tried, this many partial boards in all
+    Exhausted { nodes: u64 },
+}
+
+impl fmt::Display for Infeasible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Infeasible::Area { needed, available } => {
+                write!(
+                    f,
+                    "pieces need {} cells but only {} exist",
+                    needed, available
+                )
+            }
+            Infeasible::Oversized(piece) => write!(f, "piece {} doesn't fit at all", piece),
+            Infeasible::Exhausted { nodes } => {
+                write!(f, "no arrangement fits ({} positions searched)", nodes)
+            }
+        }
+    }
+}
+
+// Places `counts[i]` copies of each piece on a `width` by `height` board without overlaps, each
+// in any of its orientations. Cells may be left empty. Returns every placement, or why none
+// exists.
+pub fn pack(
+    width: usize,
+    height: usize,
+    pieces: &[Polyomino],
+    counts: &[usize],
+) -> Result<Vec<Placement>, Infeasible> {
+    assert_eq!(pieces.len(), counts.len(), "one count per piece");
+    assert!(pieces.iter().all(|p| p.area() > 0), "empty piece");
+    let needed: usize = (0..pieces.len())
+        .map(|i| counts[i] * pieces[i].area())
+        .sum();
+    let available = width * height;
+    if needed > available {
+        return Err(Infeasible::Area { needed, available });
+    }
+    let fits = |p: &Polyomino| {
+        let (w, h) = p.bounds();
+        (w <= width && h <= height) || (h <= width && w <= height)
+    };
+    if let Some(i) = (0..pieces.len()).find(|&i| counts[i] > 0 && !fits(&pieces[i])) {
+        return Err(Infeasible::Oversized(i));
+    }
+    if let Some(placements) = in_blocks(width, height, pieces, counts) {
+        return Ok(placements);
+    }
+    let spare = available - needed;
+    if height < width {
+        // Search along the short side instead, so less of the board is undecided at a time
+        let transposed: Vec<_> = pieces
+            .iter()
+            .map(|p| fitting(&p.transpose(), height, width))
+            .collect();
+        let mut placements = search(height, width, &transposed, counts, spare)?;
+        for placement in &mut placements {
+            placement.cells = placement.cells.iter().map(|&(x, y)| (y, x)).collect();
+        }
+        return Ok(placements);
+    }
+    let orientations: Vec<_> = pieces.iter().map(|p| fitting(p, width, height)).collect();
+    search(width, height, &orientations, counts, spare)
+}
+
+// The orientations of `piece` that fit on a `width` by `height` board.
+fn fitting(piece: &Polyomino, width: usize, height: usize) -> Vec<Polyomino> {
+    let mut orientations = piece.orientations();
+    orientations.retain(|o| {
+        let (w, h) = o.bounds();
+        w <= width && h <= height
+    });
+    orientations
+}
+
+// Runs a `Search` with each piece given as all its orientations, leaving at most `spare` cells
+// empty.
+fn search(
+    width: usize,
+    height: usize,
+    pieces: &[Vec<Polyomino>],
+    counts: &[usize],
+    spare: usize,
+) -> Result<Vec<Placement>, Infeasible> {
+    // How far past the cell it's placed on a piece can reach, in reading order
+    let reach = pieces
+        .iter()
+        .flatten()
+        .map(Polyomino::cells)
+        .flat_map(|o| o.iter().map(|&(x, y)| (y * width + x) - o[0].0 + 1))
+        .max()
+        .unwrap_or(0);
+    let mut search = Search {
+        width,
+        reach,
+        pieces,
+        counts: counts.to_vec(),
+        filled: vec![false; width * height],
+        placements: vec![],
+        failed: HashSet::new(),
+        nodes: 0,
+    };
+    if search.fill(0, spare) {
+        Ok(search.placements)
+    } else {
+        Err(Infeasible::Exhausted {
+            nodes: search.nodes,
+        })
+    }
+}
+
+// The easy case: every piece gets a box as big as the largest one, with no searching, when the
+// board has room for that many boxes.
+fn in_blocks(
+    width: usize,
+    height: usize,
+    pieces: &[Polyomino],
+    counts: &[usize],
+) -> Option<Vec<Placement>> {
+    let used = || (0..pieces.len()).filter(|&i| counts[i] > 0);
+    let tile_w = used().map(|i| pieces[i].bounds().0).max()?;
+    let tile_h = used().map(|i| pieces[i].bounds().1).max()?;
+    let across = width / tile_w;
+    if across * (height / tile_h) < counts.iter().sum() {
+        return None;
+    }
+    let mut placements = vec![];
+    for piece in used() {
+        for _ in 0..counts[piece] {
+            let k = placements.len();
+            let (x, y) = (k % across * tile_w, k / across * tile_h);
+            let cells = pieces[piece]
+                .cells()
+                .iter()
+                .map(|&(cx, cy)| (x + cx, y + cy));
+            placements.push(Placement {
+                piece,
+                cells: cells.collect(),
+            });
+        }
+    }
+    Some(placements)
+}
+
+// Backtracking over the board in reading order. The first empty cell is either covered by a piece
+// whose own first cell lands on it, or left empty while there are cells to spare. Nothing past
+// `reach` cells from there can be filled yet, so that stretch of board and the pieces left are
+// all that decide whether the rest can be finished, and dead ends are remembered by just those.
+struct Search<'a> {
+    width: usize,
+    reach: usize,
+    pieces: &'a [Vec<Polyomino>],
+    counts: Vec<usize>,
+    filled: Vec<bool>,
+    placements: Vec<Placement>,
+    failed: HashSet<(usize, Vec<bool>, Vec<usize>)>,
+    nodes: u64,
+}
+
+impl Search<'_> {
+    // The board cells `cells` would cover with its first cell at `(x, y)`, None if it hits the
+    // edge or another piece.
+    fn fit(&self, cells: &[(usize, usize)], x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
+        let height = self.filled.len() / self.width;
+        let (ax, _) = cells[0];
+        let mut output = Vec::with_capacity(cells.len());
+        for &(cx, cy) in cells {
+            let bx = (x + cx).checked_sub(ax)?;
+            let by = y + cy;
+            if bx >= self.width || by >= height || self.filled[by * self.width + bx] {
+                return None;
+            }
+            output.push((bx, by));
+        }
+        Some(output)
+    }
+
+    fn set(&mut self, cells: &[(usize, usize)], value: bool) {
+        for &(x, y) in cells {
+            self.filled[y * self.width + x] = value;
+        }
+    }
+
+    fn fill(&mut self, from: usize, spare: usize) -> bool {
+        self.nodes += 1;
+        if self.counts.iter().all(|&c| c == 0) {
+            return true;
+        }
+        let Some(pos) = (from..self.filled.len()).find(|&i| !self.filled[i]) else {
+            return false;
+        };
+        let end = self.filled.len().min(pos + self.reach);
+        let state = (pos, self.filled[pos..end].to_vec(), self.counts.clone());
+        if self.failed.contains(&state) {
+            return false;
+        }
+        let (x, y) = (pos % self.width, pos / self.width);
+        let pieces = self.pieces;
+        for (piece, orientations) in pieces.iter().enumerate() {
+            if self.counts[piece] == 0 {
+                continue;
+            }
+            for orientation in orientations {
+                let Some(cells) = self.fit(orientation.cells(), x, y) else {
+                    continue;
+                };
+                self.set(&cells, true);
+                self.counts[piece] -= 1;
+                self.placements.push(Placement { piece, cells });
+                if self.fill(pos + 1, spare) {
+                    return true;
+                }
+                let cells = self.placements.pop().unwrap().cells;
+                self.counts[piece] += 1;
+                self.set(&cells, false);
+            }
+        }
+        if spare > 0 {
+            self.filled[pos] = true;
+            if self.fill(pos + 1, spare - 1) {
+                return true;
+            }
+            self.filled[pos] = false;
+        }
+        self.failed.insert(state);
+        false
+    }
+}
+
+// Draws placements on a board, lettering the pieces A to Z in the order they're given and
+// leaving empty cells as `.`.
+pub fn render(width: usize, height: usize, placements: &[Placement]) -> Grid<char> {
+    let mut board = Grid::new(width, height, '.');
+    for (i, placement) in placements.iter().enumerate() {
+        for &cell in &placement.cells {
+            board[cell] = (b'A' + (i % 26) as u8) as char;
+        }
+    }
+    board
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tromino() -> Polyomino {
+        Polyomino::parse("#.\n##").unwrap()
+    }
+
+    #[test]
+    fn packs_tightly() {
+        // Four trominoes tile a 3x4 rectangle exactly, which the boxes alone can't manage
+        let placements = pack(3, 4, &[tromino()], &[4]).unwrap();
+        assert_eq!(placements.len(), 4);
+        let board = render(3, 4, &placements);
+        assert!(board.iter().all(|(_, &c)| c != '.'));
+
+        let roomy = pack(4, 4, &[tromino()], &[4]).unwrap();
+        assert_eq!(render(4, 4, &roomy).to_string(), "A.B.\nAABB\nC.D.\nCCDD\n");
+    }
+
+    #[test]
+    fn infeasible() {
+        let bar = Polyomino::parse("###").unwrap();
+        assert_eq!(
+            pack(3, 3, &[tromino()], &[4]),
+            Err(Infeasible::Area {
+                needed: 12,
+                available: 9
+            })
+        );
+        // A 3x3 square has room for three bars but not for a tromino next to two of them
+        let result = pack(3, 3, &[bar.clone(), tromino()], &[2, 1]);
+        assert!(matches!(result, Err(Infeasible::Exhausted { .. })));
+        assert_eq!(pack(2, 2, &[bar], &[1]), Err(Infeasible::Oversized(0)));
+    }
+
+    #[test]
+    fn fits_only_rotated() {
+        // Five wide as drawn, so only its upright orientations fit a board two wide
+        let hook = Polyomino::parse("....#\n#####").unwrap();
+        let bar = Polyomino::parse("###").unwrap();
+        let placements = pack(2, 5, &[hook.clone(), bar.clone()], &[1, 1]).unwrap();
+        assert_eq!(
+            render(2, 5, &placements)
+                .iter()
+                .filter(|&(_, &c)| c != '.')
+                .count(),
+            9
+        );
+        // And the other way round, which searches the transposed board
+        let placements = pack(5, 2, &[hook.transpose(), bar], &[1, 1]).unwrap();
+        assert_eq!(placements.iter().map(|p| p.cells.len()).sum::<usize>(), 9);
+    }
+}
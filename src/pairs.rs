@@ -0,0 +1,190 @@
+use crate::disjoint_set::DisjointSet;
+use crate::point::Coords;
+
+// A pair of point indices, `a < b`, and their squared distance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Edge {
+    pub dist: i64,
+    pub a: usize,
+    pub b: usize,
+}
+
+// Every pair of points in increasing distance order, ties broken by index, without building the
+// whole O(n²) list up front. Points are swept in x order, and each batch only collects the pairs
+// whose squared distance falls in the next band `(done, limit]`, doubling `limit` each time, so
+// asking for the closest few pairs only ever looks at nearby points.
+pub struct ClosestPairs<'a, P: Coords> {
+    points: &'a [P],
+    // Indices into `points` sorted by x
+    order: Vec<usize>,
+    done: i64,
+    limit: i64,
+    // Current band, farthest first so `pop` yields the closest
+    batch: Vec<Edge>,
+    remaining: usize,
+}
+
+impl<'a, P: Coords> ClosestPairs<'a, P> {
+    pub fn new(points: &'a [P]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        order.sort_by_key(|&i| points[i].coord(0));
+        // Neighbours in x order give an upper bound on the closest pair, so the first band is
+        // never empty.
+        let limit = order
+            .windows(2)
+            .map(|w| points[w[0]].dist_squared_to(&points[w[1]]))
+            .min()
+            .unwrap_or(0)
+            .max(1);
+        Self {
+            points,
+            order,
+            done: -1,
+            limit,
+            batch: vec![],
+            remaining: points.len() * points.len().saturating_sub(1) / 2,
+        }
+    }
+
+    fn fill_batch(&mut self) {
+        while self.batch.is_empty() {
+            let (done, limit) = (self.done, self.limit);
+            for i in 0..self.order.len() {
+                let a = self.order[i];
+                let xa = self.points[a].coord(0);
+                for &b in &self.order[i + 1..] {
+                    let dx = self.points[b].coord(0) - xa;
+                    if dx.saturating_mul(dx) > limit {
+                        break;
+                    }
+                    let dist = self.points[a].dist_squared_to(&self.points[b]);
+                    if done < dist && dist <= limit {
+                        self.batch.push(Edge {
+                            dist,
+                            a: a.min(b),
+                            b: a.max(b),
+                        });
+                    }
+                }
+            }
+            self.done = limit;
+            self.limit = limit.saturating_mul(2);
+        }
+        self.batch.sort_by(|e1, e2| e2.cmp(e1));
+    }
+}
+
+impl<P: Coords> Iterator for ClosestPairs<'_, P> {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Edge> {
+        if self.remaining == 0 {
+            return None;
+        }
+        if self.batch.is_empty() {
+            self.fill_batch();
+        }
+        self.remaining -= 1;
+        self.batch.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+pub fn closest_pairs<P: Coords>(points: &[P]) -> ClosestPairs<'_, P> {
+    ClosestPairs::new(points)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Mst {
+    // Edges that joined two components, in the order they were added
+    pub edges: Vec<Edge>,
+    // The edge that left everything in one component, None if it never happened
+    pub completing: Option<Edge>,
+}
+
+// Kruskal's algorithm over nodes `0..n`, given `edges` in increasing weight order. Stops reading
+// edges once everything is connected.
+pub fn kruskal(n: usize, edges: impl IntoIterator<Item = Edge>) -> Mst {
+    let mut mst = Mst::default();
+    if n <= 1 {
+        return mst;
+    }
+    let mut components = DisjointSet::new(n);
+    for e in edges {
+        if components.union(e.a, e.b) {
+            mst.edges.push(e);
+            if components.num_components() == 1 {
+                mst.completing = Some(e);
+                break;
+            }
+        }
+    }
+    mst
+}
+
+// Euclidean minimum spanning tree of `points`.
+pub fn point_mst<P: Coords>(points: &[P]) -> Mst {
+    kruskal(points.len(), closest_pairs(points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(points: &[[i64; 2]]) -> Vec<Edge> {
+        let mut edges = vec![];
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                let dist = points[a].dist_squared_to(&points[b]);
+                edges.push(Edge { dist, a, b });
+            }
+        }
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn pairs_in_distance_order() {
+        let points = [
+            [0, 0],
+            [10, 0],
+            [3, 4],
+            [3, 4],
+            [-7, 2],
+            [100, -50],
+            [11, 1],
+        ];
+        let lazy: Vec<Edge> = closest_pairs(&points).collect();
+        assert_eq!(lazy, brute_force(&points));
+        assert_eq!(closest_pairs(&points[..1]).count(), 0);
+    }
+
+    #[test]
+    fn mst_of_a_line() {
+        let points = [[0, 0], [5, 0], [1, 0], [3, 0]];
+        let mst = point_mst(&points);
+        assert_eq!(mst.edges.len(), 3);
+        assert_eq!(
+            mst.completing,
+            Some(Edge {
+                dist: 4,
+                a: 2,
+                b: 3
+            })
+        );
+        assert_eq!(mst.edges.iter().map(|e| e.dist).sum::<i64>(), 1 + 4 + 4);
+
+        let disconnected = kruskal(
+            3,
+            [Edge {
+                dist: 1,
+                a: 0,
+                b: 1,
+            }],
+        );
+        assert_eq!(disconnected.completing, None);
+    }
+}
@@ -0,0 +1,100 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Where and why an input failed to parse. Lines and columns are 1-based, and the file gets
+// filled in by whoever read the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    // Error pointing just past the last line of `input`.
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        Self::new(input.trim_end().lines().count() + 1, 1, "", message)
+    }
+
+    pub fn with_file(mut self, file: &Path) -> Self {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, ", found `{}`", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+// One numbered line of input, so errors can point at the token that caused them.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // 1-based column of `token`, which should be a slice of this line.
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(token), token, message)
+    }
+
+    // Error at the char starting at byte `index`.
+    pub fn char_error(&self, index: usize, message: impl Into<String>) -> ParseError {
+        let len = self.text[index..]
+            .chars()
+            .next()
+            .map_or(0, |c| c.len_utf8());
+        self.error(&self.text[index..index + len], message)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error(token, format!("expected {}", type_name::<T>())))
+    }
+}
+
+// Numbered lines of `input`, ignoring trailing blank lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.trim_end().lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
@@ -0,0 +1,396 @@
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use crate::ParseError;
+use crate::num::Integer;
+use crate::parse::Line;
+
+// The four orthogonal directions. As in `Grid`, y grows downwards, so `Up` decreases y.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    // Clockwise from `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        }
+    }
+}
+
+// Compass directions including the diagonals, with `N` being up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    // Clockwise from `N`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn opposite(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Self {
+        match d {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+fn abs_diff<T: Integer>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+// Moves `v` one unit by `d` (-1, 0 or 1), None if that leaves `T`.
+fn step_by<T: Integer>(v: T, d: i64) -> Option<T> {
+    match d {
+        -1 => v.checked_sub(T::ONE),
+        1 => v.checked_add(T::ONE),
+        _ => Some(v),
+    }
+}
+
+// Splits `token` on commas into exactly `N` coordinates.
+fn parse_coords<'a, T: FromStr, const N: usize>(
+    line: &Line<'a>,
+    token: &'a str,
+    expected: &str,
+) -> Result<[T; N], ParseError> {
+    let coords = token
+        .split(',')
+        .map(|s| line.parse::<T>(s))
+        .collect::<Result<Vec<T>, _>>()?;
+    coords
+        .try_into()
+        .map_err(|_| line.error(token, format!("expected a point like `{}`", expected)))
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Integer> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    // Parses `x,y`, pointing any error at `token` within `line`.
+    pub fn parse<'a>(line: &Line<'a>, token: &'a str) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+    {
+        let [x, y] = parse_coords(line, token, "7,1")?;
+        Ok(Self { x, y })
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    pub fn dist_squared(self, other: Self) -> T {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        dx * dx + dy * dy
+    }
+
+    // The neighbour in direction `d`, None if it can't be represented (e.g. x = -1 for usize).
+    pub fn step(self, d: impl Into<Dir8>) -> Option<Self> {
+        let (dx, dy) = d.into().offset();
+        Some(Self {
+            x: step_by(self.x, dx)?,
+            y: step_by(self.y, dy)?,
+        })
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().filter_map(move |d| self.step(d))
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().filter_map(move |d| self.step(d))
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Integer> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Integer> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Integer> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    // Parses `x,y,z`, pointing any error at `token` within `line`.
+    pub fn parse<'a>(line: &Line<'a>, token: &'a str) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+    {
+        let [x, y, z] = parse_coords(line, token, "162,817,812")?;
+        Ok(Self { x, y, z })
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    pub fn dist_squared(self, other: Self) -> T {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        let dz = abs_diff(self.z, other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+impl<T: Integer> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Integer> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// Points with `i64` coordinates along `DIM` axes, for the spatial routines that work one axis at
+// a time.
+pub trait Coords: Copy {
+    const DIM: usize;
+
+    fn coord(&self, axis: usize) -> i64;
+
+    fn dist_squared_to(&self, other: &Self) -> i64 {
+        (0..Self::DIM)
+            .map(|axis| (self.coord(axis) - other.coord(axis)).pow(2))
+            .sum()
+    }
+}
+
+impl Coords for Point2<i64> {
+    const DIM: usize = 2;
+
+    fn coord(&self, axis: usize) -> i64 {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            _ => panic!("axis {} out of range for a 2D point", axis),
+        }
+    }
+}
+
+impl Coords for Point3<i64> {
+    const DIM: usize = 3;
+
+    fn coord(&self, axis: usize) -> i64 {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z,
+            _ => panic!("axis {} out of range for a 3D point", axis),
+        }
+    }
+}
+
+impl<const N: usize> Coords for [i64; N] {
+    const DIM: usize = N;
+
+    fn coord(&self, axis: usize) -> i64 {
+        self[axis]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::lines;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point2::new(1i64, 5);
+        let b = Point2::new(4i64, 1);
+        assert_eq!(a + b, Point2::new(5, 6));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(a * 3, Point2::new(3, 15));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.dist_squared(b), 25);
+
+        let p = Point3::new(162u64, 817, 812);
+        let q = Point3::new(57u64, 618, 57);
+        assert_eq!(p.dist_squared(q), 105 * 105 + 199 * 199 + 755 * 755);
+        assert_eq!(q.manhattan(p), 105 + 199 + 755);
+        assert_eq!(p.chebyshev(q), 755);
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(Dir8::NE.opposite(), Dir8::SW);
+
+        let origin = Point2::new(0usize, 0);
+        assert_eq!(origin.step(Dir4::Up), None);
+        assert_eq!(origin.step(Dir8::SE), Some(Point2::new(1, 1)));
+        assert_eq!(
+            origin.neighbors4().collect::<Vec<_>>(),
+            [Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(Point2::new(0i64, 0).neighbors8().count(), 8);
+    }
+
+    #[test]
+    fn parse_points() {
+        let line = lines("7,1 162,817,812 1,x 1,2,3,4").next().unwrap();
+        let tokens: Vec<&str> = line.text.split(' ').collect();
+        assert_eq!(
+            Point2::<i64>::parse(&line, tokens[0]),
+            Ok(Point2::new(7, 1))
+        );
+        assert_eq!(
+            Point3::<i64>::parse(&line, tokens[1]),
+            Ok(Point3::new(162, 817, 812))
+        );
+        assert_eq!(
+            Point2::<i64>::parse(&line, tokens[2]).unwrap_err().column,
+            19
+        );
+        let e = Point3::<i64>::parse(&line, tokens[3]).unwrap_err();
+        assert_eq!(e.message, "expected a point like `162,817,812`");
+        assert_eq!(Point2::new(7, 1).to_string(), "7,1");
+    }
+}
@@ -0,0 +1,179 @@
+use crate::point::Point2;
+
+// A simple polygon whose edges are all horizontal or vertical, given by its vertices in order
+// around the boundary. Everything is exact integer arithmetic on the edges, so it doesn't matter
+// how large or sparse the coordinates are. Points on the boundary count as inside.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrthoPolygon {
+    vertices: Vec<Point2>,
+}
+
+impl OrthoPolygon {
+    // None unless there are at least four vertices and each one shares exactly one coordinate
+    // with the next (wrapping around). Self-intersection isn't checked.
+    pub fn new(vertices: Vec<Point2>) -> Option<Self> {
+        let polygon = Self { vertices };
+        let rectilinear = polygon.edges().all(|(a, b)| (a.x == b.x) != (a.y == b.y));
+        (polygon.vertices.len() >= 4 && rectilinear).then_some(polygon)
+    }
+
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    // Each edge as its two endpoints, in order around the boundary.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    // Area enclosed by the boundary, by the shoelace formula.
+    pub fn area(&self) -> i64 {
+        let twice: i64 = self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+        twice.abs() / 2
+    }
+
+    pub fn perimeter(&self) -> i64 {
+        self.edges().map(|(a, b)| a.manhattan(b)).sum()
+    }
+
+    // Lattice points strictly inside, by Pick's theorem. Every lattice point on an axis-aligned
+    // edge is on the boundary, so there are `perimeter` of those.
+    pub fn interior_points(&self) -> i64 {
+        self.area() - self.perimeter() / 2 + 1
+    }
+
+    // Lattice points inside or on the boundary, i.e. the tiles covered when each vertex is the
+    // centre of a tile.
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.perimeter()
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        self.contains_doubled(2 * p.x, 2 * p.y)
+    }
+
+    // Point-in-polygon for `(x / 2, y / 2)`, so midpoints can be tested exactly. Casts a ray
+    // towards +x and counts the vertical edges it crosses, each including its lower end only.
+    fn contains_doubled(&self, x: i64, y: i64) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (x0, x1) = (2 * a.x.min(b.x), 2 * a.x.max(b.x));
+            let (y0, y1) = (2 * a.y.min(b.y), 2 * a.y.max(b.y));
+            if x0 <= x && x <= x1 && y0 <= y && y <= y1 {
+                return true;
+            }
+            if x0 == x1 && x0 > x && y0 <= y && y < y1 {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    // Whether the axis-aligned rectangle with opposite corners `a` and `b` lies entirely inside.
+    pub fn contains_rect(&self, a: Point2, b: Point2) -> bool {
+        let (x0, x1) = (a.x.min(b.x), a.x.max(b.x));
+        let (y0, y1) = (a.y.min(b.y), a.y.max(b.y));
+        if x0 == x1 || y0 == y1 {
+            return self.contains_segment(Point2::new(x0, y0), Point2::new(x1, y1));
+        }
+        // With no edge cutting through the open rectangle, its interior is either all inside or
+        // all outside, and the centre says which.
+        let cut = self.edges().any(|(p, q)| {
+            let (ex0, ex1) = (p.x.min(q.x), p.x.max(q.x));
+            let (ey0, ey1) = (p.y.min(q.y), p.y.max(q.y));
+            if ex0 == ex1 {
+                x0 < ex0 && ex0 < x1 && ey0 < y1 && y0 < ey1
+            } else {
+                y0 < ey0 && ey0 < y1 && ex0 < x1 && x0 < ex1
+            }
+        });
+        !cut && self.contains_doubled(x0 + x1, y0 + y1)
+    }
+
+    // Whether the horizontal or vertical segment from `a` to `b` lies entirely inside. The
+    // boundary splits it into pieces that are each all in or all out, so test every piece.
+    fn contains_segment(&self, a: Point2, b: Point2) -> bool {
+        let horizontal = a.y == b.y;
+        // Work along the segment's own axis: `t` runs from `lo` to `hi` at fixed `at`
+        let along = |p: Point2| if horizontal { (p.x, p.y) } else { (p.y, p.x) };
+        let ((lo, at), (hi, _)) = (along(a), along(b));
+        let mut cuts = vec![lo, hi];
+        for (p, q) in self.edges() {
+            let ((t0, s0), (t1, s1)) = (along(p), along(q));
+            if s0.min(s1) <= at && at <= s0.max(s1) {
+                cuts.extend([t0, t1].into_iter().filter(|&t| lo < t && t < hi));
+            }
+        }
+        cuts.sort();
+        cuts.dedup();
+        let point = |t2: i64| {
+            if horizontal {
+                self.contains_doubled(t2, 2 * at)
+            } else {
+                self.contains_doubled(2 * at, t2)
+            }
+        };
+        cuts.iter().all(|&t| point(2 * t)) && cuts.windows(2).all(|w| point(w[0] + w[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The day 9 example loop
+    fn example() -> OrthoPolygon {
+        let v = [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ];
+        OrthoPolygon::new(v.into_iter().map(Point2::from).collect()).unwrap()
+    }
+
+    #[test]
+    fn area_and_points() {
+        let p = example();
+        assert_eq!(p.area(), 4 * 2 + 9 * 2 + 2 * 2);
+        assert_eq!(p.perimeter(), 30);
+        // The red and green tiles drawn in the puzzle
+        assert_eq!(p.lattice_points(), 46);
+        assert!(p.contains(Point2::new(8, 2)) && p.contains(Point2::new(2, 4)));
+        assert!(!p.contains(Point2::new(8, 6)) && !p.contains(Point2::new(1, 4)));
+        assert!(OrthoPolygon::new(vec![Point2::new(0, 0), Point2::new(1, 1)]).is_none());
+    }
+
+    #[test]
+    fn rectangles() {
+        let p = example();
+        let rect = |a: (i64, i64), b: (i64, i64)| p.contains_rect(a.into(), b.into());
+        assert!(rect((9, 5), (2, 3)));
+        assert!(rect((11, 1), (9, 7)));
+        assert!(rect((7, 1), (11, 1)));
+        assert!(!rect((2, 5), (11, 1)));
+        assert!(!rect((2, 3), (9, 7)));
+        assert!(rect((2, 5), (11, 5)) && !rect((7, 6), (11, 6)));
+
+        // A U whose notch has all four corners on the boundary but lies outside
+        let u = [
+            (0, 0),
+            (6, 0),
+            (6, 4),
+            (4, 4),
+            (4, 2),
+            (2, 2),
+            (2, 4),
+            (0, 4),
+        ];
+        let u = OrthoPolygon::new(u.into_iter().map(Point2::from).collect()).unwrap();
+        assert!(!u.contains_rect(Point2::new(2, 2), Point2::new(4, 4)));
+        assert!(u.contains_rect(Point2::new(0, 0), Point2::new(6, 2)));
+        assert!(!u.contains_rect(Point2::new(0, 3), Point2::new(6, 3)));
+    }
+}
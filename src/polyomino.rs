@@ -0,0 +1,150 @@
+use std::fmt;
+
+use crate::parse::{Line, ParseError, lines};
+
+// A set of grid cells, shifted so the smallest x and y are both zero and kept in reading order,
+// so two polyominoes are equal exactly when one is a translation of the other. Connectedness
+// isn't checked.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Polyomino {
+    cells: Vec<(usize, usize)>,
+}
+
+impl Polyomino {
+    pub fn new(cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let mut cells: Vec<_> = cells.into_iter().collect();
+        let x0 = cells.iter().map(|c| c.0).min().unwrap_or(0);
+        let y0 = cells.iter().map(|c| c.1).min().unwrap_or(0);
+        for (x, y) in &mut cells {
+            *x -= x0;
+            *y -= y0;
+        }
+        cells.sort_by_key(|&(x, y)| (y, x));
+        cells.dedup();
+        Self { cells }
+    }
+
+    // Parses `#` for a cell and `.` for a gap, one row per line.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Self::from_lines(&lines(s).collect::<Vec<_>>())
+    }
+
+    // Like `parse`, for rows that are part of a bigger input so errors point into it.
+    pub fn from_lines(rows: &[Line<'_>]) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        for (y, line) in rows.iter().enumerate() {
+            for (x, (i, c)) in line.text.char_indices().enumerate() {
+                match c {
+                    '#' => cells.push((x, y)),
+                    '.' => {}
+                    _ => return Err(line.char_error(i, "expected `#` or `.`")),
+                }
+            }
+        }
+        if cells.is_empty() {
+            let message = "expected a shape with at least one `#`";
+            return Err(match rows.last() {
+                Some(line) => line.error(line.text, message),
+                None => ParseError::new(1, 1, "", message),
+            });
+        }
+        Ok(Self::new(cells))
+    }
+
+    // The cells in reading order, top row first.
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    // Width and height of the bounding box.
+    pub fn bounds(&self) -> (usize, usize) {
+        let w = self.cells.iter().map(|c| c.0 + 1).max().unwrap_or(0);
+        let h = self.cells.iter().map(|c| c.1 + 1).max().unwrap_or(0);
+        (w, h)
+    }
+
+    // Quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let (_, h) = self.bounds();
+        Self::new(self.cells.iter().map(|&(x, y)| (h - 1 - y, x)))
+    }
+
+    // Mirror image left to right.
+    pub fn flip_lr(&self) -> Self {
+        let (w, _) = self.bounds();
+        Self::new(self.cells.iter().map(|&(x, y)| (w - 1 - x, y)))
+    }
+
+    // Mirror image across the main diagonal, swapping x and y.
+    pub fn transpose(&self) -> Self {
+        Self::new(self.cells.iter().map(|&(x, y)| (y, x)))
+    }
+
+    // Every distinct rotation and reflection, starting with this one. Symmetric shapes have
+    // fewer than eight.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut output: Vec<Self> = vec![];
+        let mut shape = self.clone();
+        for _ in 0..2 {
+            for _ in 0..4 {
+                if !output.contains(&shape) {
+                    output.push(shape.clone());
+                }
+                shape = shape.rotate_cw();
+            }
+            shape = shape.flip_lr();
+        }
+        output
+    }
+}
+
+// The same `#` and `.` art that `parse` reads, one line per row.
+impl fmt::Display for Polyomino {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (w, h) = self.bounds();
+        let mut cells = self.cells.iter().peekable();
+        for y in 0..h {
+            for x in 0..w {
+                if cells.next_if_eq(&&(x, y)).is_some() {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orientations() {
+        let s = Polyomino::parse(".##\n##.\n").unwrap();
+        assert_eq!((s.area(), s.bounds()), (4, (3, 2)));
+        assert_eq!(s.to_string(), ".##\n##.\n");
+        assert_eq!(s.rotate_cw().to_string(), "#.\n##\n.#\n");
+        assert_eq!(s.orientations().len(), 4);
+        assert_eq!(s.transpose(), s.rotate_cw().flip_lr());
+
+        let l = Polyomino::new([(5, 5), (5, 6), (5, 7), (6, 7)]);
+        assert_eq!(l.cells()[0], (0, 0));
+        assert_eq!(l.orientations().len(), 8);
+        let square = Polyomino::parse("##\n##").unwrap();
+        assert_eq!(square.orientations(), [square]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Polyomino::parse("#.\n.x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+        assert!(Polyomino::parse("..\n").is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use std::cmp::{max, min};
+use std::collections::BTreeMap;
+
+use crate::num::Integer;
+
+// Closed interval [start, end]. Anything with start > end is empty, and all empty ranges are
+// stored the same way so they compare equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Range<T = usize> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Range<T> {
+    // Closed range between two endpoints given in either order.
+    pub fn new(a: T, b: T) -> Self {
+        Self {
+            start: min(a, b),
+            end: max(a, b),
+        }
+    }
+
+    // [start, end], empty if start > end.
+    pub fn closed(start: T, end: T) -> Self {
+        if start > end {
+            Self::empty()
+        } else {
+            Self { start, end }
+        }
+    }
+
+    // [start, end), empty if start >= end.
+    pub fn half_open(start: T, end: T) -> Self {
+        if start >= end {
+            Self::empty()
+        } else {
+            // end > start >= MIN, so this can't underflow
+            Self {
+                start,
+                end: end.checked_sub(T::ONE).unwrap(),
+            }
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            start: T::ONE,
+            end: T::ZERO,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    // Endpoints are only meaningful for non-empty ranges.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    // Exclusive end, None for an empty range or if it doesn't fit in T.
+    pub fn end_exclusive(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.end.checked_add(T::ONE)
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn intersect(&self, other: Range<T>) -> Option<Range<T>> {
+        if self.is_empty() || other.is_empty() || self.start > other.end || other.start > self.end {
+            None
+        } else {
+            Some(Range::new(
+                max(self.start, other.start),
+                min(self.end, other.end),
+            ))
+        }
+    }
+
+    pub fn union(&self, other: Range<T>) -> Vec<Range<T>> {
+        if other.is_empty() {
+            [*self].into_iter().filter(|r| !r.is_empty()).collect()
+        } else if self.is_empty() {
+            vec![other]
+        } else if self.start > other.end {
+            vec![other, *self]
+        } else if other.start > self.end {
+            vec![*self, other]
+        } else {
+            vec![Range::new(
+                min(self.start, other.start),
+                max(self.end, other.end),
+            )]
+        }
+    }
+
+    // Number of values in the range, None if that count doesn't fit in T.
+    pub fn len(&self) -> Option<T> {
+        if self.is_empty() {
+            Some(T::ZERO)
+        } else {
+            self.end.checked_sub(self.start)?.checked_add(T::ONE)
+        }
+    }
+}
+
+pub fn union<T: Integer>(ranges: &[Range<T>]) -> Vec<Range<T>> {
+    ranges
+        .iter()
+        .copied()
+        .collect::<RangeSet<T>>()
+        .iter()
+        .collect()
+}
+
+// Set of disjoint, sorted, closed ranges keyed by their start value. Touching ranges are merged
+// on insert, so the stored form is always canonical.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeSet<T = usize> {
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T: Integer> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+        // Absorb every stored range that overlaps or touches the new one, walking down from the
+        // last range that starts at or before `end + 1`.
+        while let Some((&s, &e)) = self.ranges.range(..=end.saturating_add(T::ONE)).next_back() {
+            if e.saturating_add(T::ONE) < start {
+                break;
+            }
+            start = min(start, s);
+            end = max(end, e);
+            self.ranges.remove(&s);
+        }
+        self.ranges.insert(start, end);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        while let Some((&s, &e)) = self.ranges.range(..=range.end).next_back() {
+            if e < range.start {
+                break;
+            }
+            self.ranges.remove(&s);
+            if e > range.end {
+                self.ranges
+                    .insert(range.end.checked_add(T::ONE).unwrap(), e);
+            }
+            if s < range.start {
+                // Nothing further left can overlap once we've split off the low end
+                self.ranges
+                    .insert(s, range.start.checked_sub(T::ONE).unwrap());
+                break;
+            }
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &e)| e >= value)
+    }
+
+    // The range stored in the set that holds `value`, if any.
+    pub fn range_containing(&self, value: T) -> Option<Range<T>> {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .filter(|(_, e)| **e >= value)
+            .map(|(&s, &e)| Range::new(s, e))
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut output = self.clone();
+        output.extend(other.iter());
+        output
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut output = RangeSet::new();
+        let mut a = self.iter().peekable();
+        let mut b = other.iter().peekable();
+        while let (Some(ra), Some(rb)) = (a.peek(), b.peek()) {
+            if let Some(r) = ra.intersect(*rb) {
+                output.insert(r);
+            }
+            // Advance whichever range finishes first, it can't overlap anything else
+            if ra.end < rb.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        output
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut output = self.clone();
+        for r in other.iter() {
+            output.remove(r);
+        }
+        output
+    }
+
+    // Everything inside `bounds` that isn't in the set.
+    pub fn complement(&self, bounds: Range<T>) -> RangeSet<T> {
+        let mut output = RangeSet::new();
+        output.insert(bounds);
+        output.difference(self)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().map(|(&s, &e)| Range::new(s, e))
+    }
+
+    pub fn num_ranges(&self) -> usize {
+        self.ranges.len()
+    }
+
+    // Total count of values covered by the set, None if that count doesn't fit in T.
+    pub fn len(&self) -> Option<T> {
+        self.iter()
+            .try_fold(T::ZERO, |acc, r| acc.checked_add(r.len()?))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut output = RangeSet::new();
+        output.extend(iter);
+        output
+    }
+}
+
+impl<T: Integer> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for r in iter {
+            self.insert(r);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(usize, usize)]) -> RangeSet {
+        ranges.iter().map(|&(a, b)| Range::new(a, b)).collect()
+    }
+
+    fn pairs(s: &RangeSet) -> Vec<(usize, usize)> {
+        s.iter().map(|r| (r.start(), r.end())).collect()
+    }
+
+    #[test]
+    fn generic_ranges() {
+        let r = Range::half_open(-3_i64, 2);
+        assert_eq!((r.start(), r.end(), r.end_exclusive()), (-3, 1, Some(2)));
+        assert_eq!(r.len(), Some(5));
+        assert!(r.contains(-3) && r.contains(1) && !r.contains(2));
+        assert_eq!(Range::new(4_i64, -4), Range::closed(-4, 4));
+
+        // Empty ranges all compare equal, whatever they were built from
+        assert_eq!(Range::half_open(5_i32, 5), Range::<i32>::empty());
+        assert_eq!(Range::closed(7_i32, -7), Range::empty());
+        let empty = Range::<u128>::empty();
+        assert!(empty.is_empty() && !empty.contains(0) && !empty.contains(1));
+        assert_eq!((empty.len(), empty.end_exclusive()), (Some(0), None));
+        assert_eq!(Range::new(1_u128, 9).intersect(empty), None);
+
+        // Counts and exclusive ends past T::MAX don't fit
+        let all = Range::closed(0_u128, u128::MAX);
+        assert_eq!((all.len(), all.end_exclusive()), (None, None));
+        assert_eq!(Range::closed(1_u128, u128::MAX).len(), Some(u128::MAX));
+        assert_eq!(Range::closed(i8::MIN, i8::MAX).len(), None);
+        assert_eq!(Range::closed(-64_i8, 62).len(), Some(127));
+        let top: RangeSet<u128> = [Range::closed(u128::MAX - 1, u128::MAX)]
+            .into_iter()
+            .collect();
+        assert_eq!(top.len(), Some(2));
+    }
+
+    #[test]
+    fn insert_merges() {
+        // Overlapping and touching ranges merge, a gap of one value keeps them apart
+        let s = set(&[(10, 14), (16, 20), (12, 18), (1, 3), (4, 5), (7, 8)]);
+        assert_eq!(pairs(&s), [(1, 5), (7, 8), (10, 20)]);
+        assert_eq!(s.len(), Some(5 + 2 + 11));
+        assert!(s.contains(5) && !s.contains(6) && s.contains(20) && !s.contains(21));
+        assert_eq!(s.range_containing(15), Some(Range::new(10, 20)));
+        assert_eq!(s.range_containing(9), None);
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut s = set(&[(1, 5), (10, 20)]);
+        s.remove(Range::new(12, 14));
+        assert_eq!(pairs(&s), [(1, 5), (10, 11), (15, 20)]);
+        s.remove(Range::new(4, 10));
+        assert_eq!(pairs(&s), [(1, 3), (11, 11), (15, 20)]);
+        s.remove(Range::new(0, 30));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (18, 25)]);
+        assert_eq!(pairs(&a.union(&b)), [(1, 25)]);
+        assert_eq!(pairs(&a.intersection(&b)), [(4, 5), (10, 12), (18, 20)]);
+        assert_eq!(pairs(&a.difference(&b)), [(1, 3), (13, 17)]);
+        assert_eq!(
+            pairs(&a.complement(Range::new(0, 22))),
+            [(0, 0), (6, 9), (21, 22)]
+        );
+        assert_eq!(
+            union(&[Range::new(3, 1), Range::new(2, 6)]),
+            [Range::new(1, 6)]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// Exact fraction, always in lowest terms with a positive denominator. The checked operations
+// return None on overflow, and the operators panic rather than wrap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+// Only overflows for 2¹²⁷, which needs both inputs to be `i128::MIN` or zero.
+fn gcd(a: i128, b: i128) -> Option<i128> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    i128::try_from(a).ok()
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    pub fn new(num: i128, den: i128) -> Self {
+        Self::checked_new(num, den).expect("rational with zero denominator or out of range")
+    }
+
+    // None if `den` is zero or the reduced fraction doesn't fit.
+    pub fn checked_new(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        let g = gcd(num, den)?;
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Some(Rational {
+                num: num.checked_neg()?,
+                den: den.checked_neg()?,
+            })
+        } else {
+            Some(Rational { num, den })
+        }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    pub fn floor(self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    pub fn ceil(self) -> i128 {
+        // Rounding the floor up, as negating `self` overflows for `i128::MIN`
+        self.floor() + (self.num.rem_euclid(self.den) != 0) as i128
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        // Working over the lcm of the denominators keeps the intermediates small
+        let g = gcd(self.den, rhs.den)?;
+        let (a, b) = (self.den / g, rhs.den / g);
+        let num = self
+            .num
+            .checked_mul(b)?
+            .checked_add(rhs.num.checked_mul(a)?)?;
+        Self::checked_new(num, self.den.checked_mul(b)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cancel across first so the products only overflow if the result would
+        let g1 = gcd(self.num, rhs.den)?;
+        let g2 = gcd(rhs.num, self.den)?;
+        let num = (self.num / g1).checked_mul(rhs.num / g2)?;
+        let den = (self.den / g2).checked_mul(rhs.den / g1)?;
+        Self::checked_new(num, den)
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    // None for zero.
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::checked_new(self.den, self.num)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational {
+            num: n as i128,
+            den: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        self.checked_add(rhs).expect("rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self.checked_sub(rhs).expect("rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        self.checked_mul(rhs).expect("rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Rational {
+        assert!(!rhs.is_zero(), "rational division by zero");
+        self.checked_div(rhs).expect("rational overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("rational overflow")
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Comparing integer parts first keeps the cross products for the remainders in range
+        let (a, b) = (self.floor(), other.floor());
+        if a != b {
+            return a.cmp(&b);
+        }
+        let ra = self.num - a * self.den;
+        let rb = other.num - b * other.den;
+        match (ra.checked_mul(other.den), rb.checked_mul(self.den)) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            // Both remainders are in [0, 1): compare the reciprocals the other way round
+            _ => Rational::new(other.den, rb).cmp(&Rational::new(self.den, ra)),
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half - Rational::ONE, Rational::new(-1, 2));
+        assert_eq!(half * Rational::from(4), Rational::from(2));
+        assert_eq!(Rational::from(3) / Rational::from(6), half);
+        assert_eq!(
+            (Rational::new(-7, 2).floor(), Rational::new(-7, 2).ceil()),
+            (-4, -3)
+        );
+        assert_eq!(Rational::new(i128::MIN, 1).ceil(), i128::MIN);
+        assert!(Rational::new(1, 3) < half && -half < Rational::ZERO);
+        assert_eq!(Rational::new(-7, 2).to_string(), "-7/2");
+        assert_eq!(Rational::from(5).to_string(), "5");
+        assert_eq!(Rational::ZERO.checked_recip(), None);
+    }
+
+    #[test]
+    fn overflow_is_detected() {
+        let big = Rational::from(i64::MAX) * Rational::from(i64::MAX);
+        assert_eq!(big.checked_mul(Rational::from(4)), None);
+        assert_eq!(Rational::new(i128::MAX, 1).checked_add(Rational::ONE), None);
+        // Cancelling first keeps this in range
+        let r = Rational::new(i128::MAX, 3) * Rational::new(3, i128::MAX);
+        assert_eq!(r, Rational::ONE);
+        let m = i128::MAX;
+        assert!(Rational::new(m - 2, m - 1) < Rational::new(m - 1, m));
+    }
+}
@@ -0,0 +1,149 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::point::Point2;
+use crate::polygon::OrthoPolygon;
+
+// An axis-aligned rectangle of lattice points, `min` and `max` inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Rect {
+    // The rectangle with `a` and `b` at opposite corners, in either order.
+    pub fn new(a: Point2, b: Point2) -> Self {
+        Self {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    // Number of lattice points covered.
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+}
+
+// The `k` largest rectangles with two of `corners` at opposite corners that `accept` allows,
+// biggest first, plus any more that tie with the last of them. Equal areas come out in order of
+// corner index. The heap holds just the best untried partner of each corner, and a corner's
+// partners are only sorted once it reaches the top, so the work stops with the answer instead of
+// covering every pair.
+pub fn largest_rects(
+    corners: &[Point2],
+    k: usize,
+    mut accept: impl FnMut(&Rect) -> bool,
+) -> Vec<Rect> {
+    let n = corners.len();
+    let area = |i: usize, j: usize| Rect::new(corners[i], corners[j]).area();
+    let mut heap = BinaryHeap::new();
+    for i in 0..n {
+        if let Some(j) = (i + 1..n).max_by_key(|&j| (area(i, j), Reverse(j))) {
+            heap.push((area(i, j), Reverse((i, j))));
+        }
+    }
+    // Partners of `i` after it, best first, and how many of them have been tried
+    let mut partners: Vec<Option<Vec<usize>>> = vec![None; n];
+    let mut tried = vec![0; n];
+    let mut output: Vec<Rect> = vec![];
+    while let Some((a, Reverse((i, j)))) = heap.pop() {
+        if output.len() >= k && output.last().is_none_or(|last| last.area() > a) {
+            break;
+        }
+        let r = Rect::new(corners[i], corners[j]);
+        if accept(&r) {
+            output.push(r);
+        }
+        let order = partners[i].get_or_insert_with(|| {
+            let mut order: Vec<usize> = (i + 1..n).collect();
+            order.sort_by_key(|&j| (Reverse(area(i, j)), j));
+            order
+        });
+        tried[i] += 1;
+        if let Some(&j) = order.get(tried[i]) {
+            heap.push((area(i, j), Reverse((i, j))));
+        }
+    }
+    output
+}
+
+pub fn largest_rect(corners: &[Point2], accept: impl FnMut(&Rect) -> bool) -> Option<Rect> {
+    largest_rects(corners, 1, accept).into_iter().next()
+}
+
+// The largest rectangle between two of `corners` that lies entirely inside `polygon`.
+pub fn largest_rect_in(corners: &[Point2], polygon: &OrthoPolygon) -> Option<Rect> {
+    largest_rect(corners, |r| polygon.contains_rect(r.min, r.max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(v: &[(i64, i64)]) -> Vec<Point2> {
+        v.iter().map(|&p| p.into()).collect()
+    }
+
+    #[test]
+    fn best_and_ties() {
+        let corners = points(&[(0, 0), (3, 1), (1, 3), (10, 10)]);
+        let best = largest_rect(&corners, |_| true).unwrap();
+        assert_eq!(
+            (best.min, best.max, best.area()),
+            (corners[0], corners[3], 121)
+        );
+
+        // An 8x10 and a 10x8 tie once squares are ruled out
+        let oblong = largest_rects(&corners, 1, |r| r.width() != r.height());
+        assert_eq!(
+            oblong.iter().map(|r| r.area()).collect::<Vec<_>>(),
+            [80, 80]
+        );
+        assert_eq!((oblong[0].min, oblong[1].min), (corners[1], corners[2]));
+        assert!(oblong[0].contains(Point2::new(5, 5)) && !oblong[0].contains(corners[0]));
+        assert_eq!(largest_rects(&corners, 0, |_| true), []);
+        assert_eq!(largest_rect(&corners, |_| false), None);
+
+        // Asking for more than there are walks every pair once, biggest first
+        let mut seen = vec![];
+        largest_rects(&corners, 7, |r| {
+            seen.push(r.area());
+            true
+        });
+        assert_eq!(seen, [121, 80, 80, 9, 8, 8]);
+    }
+
+    #[test]
+    fn inside_polygon() {
+        let loop_ = points(&[
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]);
+        let polygon = OrthoPolygon::new(loop_.clone()).unwrap();
+        let best = largest_rect_in(&loop_, &polygon).unwrap();
+        assert_eq!(
+            (best.area(), best.min, best.max),
+            (24, Point2::new(2, 3), Point2::new(9, 5))
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crate::answers::{InputKind, Registry, Verdict, check};
+use crate::input::{InputError, Source, input_dir};
+use crate::solution::{Answers, Outcome, Part, Solution, SolveError, Timings, solve};
+
+// Type-erased handle on a day's `Solution`, so the runner can loop over every day.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    pub solve: fn(&str, InputKind, Option<Part>) -> Result<Outcome, SolveError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve::<S>,
+        }
+    }
+}
+
+// Anything that stops a day from producing answers.
+#[derive(Debug)]
+pub enum RunError {
+    Input(InputError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Input(e) => write!(f, "{}", e),
+            RunError::Solve(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+impl From<InputError> for RunError {
+    fn from(e: InputError) -> Self {
+        RunError::Input(e)
+    }
+}
+
+impl From<SolveError> for RunError {
+    fn from(e: SolveError) -> Self {
+        RunError::Solve(e)
+    }
+}
+
+pub fn input_path(day: u32) -> PathBuf {
+    input_dir().join(format!("day{:02}.txt", day))
+}
+
+// Examples live in `dayNNa.txt`, unless part 2 has its own in `dayNNb.txt`.
+pub fn example_path(day: u32, part: Part) -> PathBuf {
+    let b = input_dir().join(format!("day{:02}b.txt", day));
+    if part == Part::Two && b.exists() {
+        b
+    } else {
+        input_dir().join(format!("day{:02}a.txt", day))
+    }
+}
+
+// Solves an already read input, naming `source` in any error.
+pub fn solve_str(
+    day: &Day,
+    input: &str,
+    source: &Source,
+    kind: InputKind,
+    part: Option<Part>,
+) -> Result<Outcome, SolveError> {
+    (day.solve)(input, kind, part).map_err(|e| e.with_file(source.path()))
+}
+
+pub fn solve_input(
+    day: &Day,
+    source: &Source,
+    kind: InputKind,
+    part: Option<Part>,
+) -> Result<Outcome, RunError> {
+    Ok(solve_str(day, &source.read()?, source, kind, part)?)
+}
+
+pub fn solve_examples(day: &Day, part: Option<Part>) -> Result<Outcome, RunError> {
+    if example_path(day.day, Part::One) == example_path(day.day, Part::Two) {
+        let source = Source::File(example_path(day.day, Part::One));
+        return solve_input(day, &source, InputKind::Example, part);
+    }
+    // Each part has its own example, which may not even parse for the other part
+    let mut outcome = Outcome::default();
+    for p in Part::selected(part) {
+        let source = Source::File(example_path(day.day, p));
+        let solved = solve_input(day, &source, InputKind::Example, Some(p))?;
+        outcome.answers.set(p, solved.answers.get(p).cloned());
+        outcome.timings.parse += solved.timings.parse;
+        outcome.timings.set(p, solved.timings.get(p));
+    }
+    Ok(outcome)
+}
+
+pub fn millis(d: Duration) -> f64 {
+    1000.0 * d.as_secs_f64()
+}
+
+fn print_answers(answers: &Answers) {
+    for p in Part::ALL {
+        if let Some(answer) = answers.get(p) {
+            println!("Part {}: {}", p.number(), answer);
+        }
+    }
+}
+
+fn print_timings(timings: &Timings) {
+    let mut phases = vec![format!("parse {:.3} ms", millis(timings.parse))];
+    for p in Part::ALL {
+        if let Some(time) = timings.get(p) {
+            phases.push(format!("part {} {:.3} ms", p.number(), millis(time)));
+        }
+    }
+    println!("Time: {}", phases.join(", "));
+}
+
+fn print_outcome(outcome: &Outcome) {
+    print_answers(&outcome.answers);
+    print_timings(&outcome.timings);
+}
+
+// Runs the examples and then the real input, or just `input` if one is given, solved as `kind`.
+pub fn run(
+    day: &Day,
+    part: Option<Part>,
+    input: Option<&Source>,
+    kind: InputKind,
+) -> Result<(), RunError> {
+    let t0 = Instant::now();
+
+    if let Some(source) = input {
+        print_outcome(&solve_input(day, source, kind, part)?);
+    } else {
+        println!("Examples:");
+        print_outcome(&solve_examples(day, part)?);
+
+        println!("Challenges:");
+        let source = Source::File(input_path(day.day));
+        print_outcome(&solve_input(day, &source, InputKind::Real, part)?);
+    }
+
+    println!("Total time: {:.3} ms", millis(t0.elapsed()));
+    Ok(())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "unknown panic"
+    }
+}
+
+// Solves a single part, turning parse errors and panics into an error message.
+fn solve_checked(day: &Day, kind: InputKind, part: Part) -> Result<String, String> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match kind {
+        InputKind::Example => solve_examples(day, Some(part)),
+        InputKind::Real => {
+            let source = Source::File(input_path(day.day));
+            solve_input(day, &source, kind, Some(part))
+        }
+    }));
+    match result {
+        Ok(Ok(outcome)) => outcome
+            .answers
+            .get(part)
+            .cloned()
+            .ok_or_else(|| "no answer".to_string()),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+// Checks every selected puzzle against the registry, printing one line each. Returns false if
+// anything failed.
+pub fn verify(days: &[Day], part: Option<Part>, registry: &Registry) -> bool {
+    // Panics are reported as failures, so keep the default hook from printing them too
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        for kind in InputKind::ALL {
+            for p in Part::selected(part) {
+                let expected = registry.get(day.day, p, kind);
+                let label = format!("Day {:02} part {} {}", day.day, p.number(), kind);
+                match check(expected, solve_checked(day, kind, p)) {
+                    Verdict::Pass => {
+                        passed += 1;
+                        println!("{}: pass", label);
+                    }
+                    Verdict::Fail { expected, actual } => {
+                        failed += 1;
+                        println!(
+                            "{}: FAIL (expected {}, got {})",
+                            label,
+                            expected.as_deref().unwrap_or("nothing"),
+                            actual.unwrap_or_else(|e| e)
+                        );
+                    }
+                    Verdict::Missing { actual } => {
+                        missing += 1;
+                        println!("{}: missing (got {})", label, actual);
+                    }
+                }
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    failed == 0
+}
+
+// Entry point for the single-day binaries, which take an optional input path or `-` for stdin.
+pub fn main(day: &Day) -> ExitCode {
+    let input = std::env::args().nth(1).map(|arg| Source::from_arg(&arg));
+    match run(day, None, input.as_ref(), InputKind::Real) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
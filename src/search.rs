@@ -0,0 +1,203 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::num::Integer;
+
+// A cheapest route found by a search: its total cost and every state along it, start to goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+// Walks `parent` links back from `end` to the start, whose parent is itself.
+fn reconstruct<S: Clone>(states: &[S], parent: &[usize], end: usize) -> Vec<S> {
+    let mut path = vec![states[end].clone()];
+    let mut i = end;
+    while parent[i] != i {
+        i = parent[i];
+        path.push(states[i].clone());
+    }
+    path.reverse();
+    path
+}
+
+// Every state reachable from `start`, including `start` itself.
+pub fn reachable<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+    while let Some(s) = stack.pop() {
+        for next in neighbors(&s) {
+            if seen.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+    seen
+}
+
+// Breadth-first search for the fewest steps from `start` to any state where `is_goal` holds.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut parent = vec![0];
+    let mut depth = vec![0];
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&states[i]) {
+            return Some(Path {
+                cost: depth[i],
+                states: reconstruct(&states, &parent, i),
+            });
+        }
+        for next in neighbors(&states[i]) {
+            if !ids.contains_key(&next) {
+                let j = states.len();
+                ids.insert(next.clone(), j);
+                states.push(next);
+                parent.push(i);
+                depth.push(depth[i] + 1);
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+// Dijkstra's algorithm: the cheapest path from `start` to a goal, where `neighbors` yields each
+// next state with the (non-negative) cost of stepping to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbors, |_| C::ZERO, is_goal)
+}
+
+// A*: Dijkstra's algorithm guided by `heuristic`, an estimate of the remaining cost that must
+// never overestimate it, or the path found may not be the cheapest. A state that's already been
+// expanded is opened again if a cheaper way to it turns up, which only happens when the estimate
+// drops by more than a step costs somewhere.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut states = vec![start];
+    let mut parent = vec![0];
+    let mut dist = vec![C::ZERO];
+    let mut done = vec![false];
+    while let Some(Reverse((_, i))) = heap.pop() {
+        if done[i] {
+            continue;
+        }
+        done[i] = true;
+        if is_goal(&states[i]) {
+            return Some(Path {
+                cost: dist[i],
+                states: reconstruct(&states, &parent, i),
+            });
+        }
+        for (next, step) in neighbors(&states[i]) {
+            let d = dist[i] + step;
+            let j = match ids.get(&next) {
+                Some(&j) if dist[j] <= d => continue,
+                Some(&j) => {
+                    done[j] = false;
+                    j
+                }
+                None => {
+                    let j = states.len();
+                    ids.insert(next.clone(), j);
+                    states.push(next);
+                    parent.push(i);
+                    dist.push(d);
+                    done.push(false);
+                    j
+                }
+            };
+            parent[j] = i;
+            dist[j] = d;
+            heap.push(Reverse((d + heuristic(&states[j]), j)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E
+";
+
+    #[test]
+    fn maze() {
+        let maze = Grid::parse(MAZE, Ok::<_, &str>).unwrap();
+        let open = |&(x, y): &(usize, usize)| {
+            maze.neighbors4(x, y)
+                .filter(|&p| maze[p] != '#')
+                .collect::<Vec<_>>()
+        };
+        let path = bfs((0, 0), open, |&p| maze[p] == 'E').unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!((path.states[0], path.states[12]), ((0, 0), (7, 3)));
+
+        let weighted = |p: &(usize, usize)| open(p).into_iter().map(|q| (q, 2));
+        let goal = |&p: &(usize, usize)| maze[p] == 'E';
+        assert_eq!(dijkstra((0, 0), weighted, goal).unwrap().cost, 24);
+        let manhattan = |&(x, y): &(usize, usize)| 2 * ((7 - x) + (3 - y));
+        let path = astar((0, 0), weighted, manhattan, goal).unwrap();
+        assert_eq!(path.cost, 24);
+
+        assert_eq!(reachable((0, 0), open).len(), 22);
+        assert_eq!(bfs((0, 0), open, |&p| p == (2, 0)), None);
+    }
+
+    #[test]
+    fn inconsistent_heuristic() {
+        // B looks far from the goal, so C is first reached the long way round through A
+        let edges = |&s: &char| match s {
+            'S' => vec![('A', 1), ('B', 2)],
+            'A' => vec![('C', 3)],
+            'B' => vec![('C', 1)],
+            'C' => vec![('G', 3)],
+            _ => vec![],
+        };
+        let h = |&s: &char| if s == 'B' { 4 } else { 0 };
+        let path = astar('S', edges, h, |&s| s == 'G').unwrap();
+        assert_eq!((path.cost, path.states), (6, vec!['S', 'B', 'C', 'G']));
+    }
+}
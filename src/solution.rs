@@ -0,0 +1,202 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::ParseError;
+use crate::answers::InputKind;
+
+// One day's puzzle. `parse` gets the raw contents of an input file and rejects anything
+// malformed, and the parts print whatever answer type suits the puzzle, or say why the input
+// has no answer.
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    // `parse` told whether this is the puzzle's example or a real input, for puzzles that run
+    // their example with smaller parameters than the text itself gives. Most don't care.
+    fn parse_kind(input: &str, _kind: InputKind) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, NoAnswer>;
+    fn part2(input: &Self::Input) -> Result<impl Display, NoAnswer>;
+}
+
+// Why a part has no answer for an input that parsed fine, e.g. a puzzle that can't be solved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoAnswer(pub String);
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for NoAnswer {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u32) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    // The parts selected by an optional `--part` filter.
+    pub fn selected(part: Option<Part>) -> Vec<Part> {
+        match part {
+            Some(p) => vec![p],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+// Answers for one input, None for parts that weren't run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Option<String>) {
+        match part {
+            Part::One => self.part1 = answer,
+            Part::Two => self.part2 = answer,
+        }
+    }
+}
+
+// Wall time spent in each phase, None for parts that weren't run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn get(&self, part: Part) -> Option<Duration> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    pub fn set(&mut self, part: Part, time: Option<Duration>) {
+        match part {
+            Part::One => self.part1 = time,
+            Part::Two => self.part2 = time,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub answers: Answers,
+    pub timings: Timings,
+}
+
+// Anything that stops `solve` from answering.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    NoAnswer {
+        file: Option<PathBuf>,
+        part: Part,
+        reason: NoAnswer,
+    },
+}
+
+impl SolveError {
+    pub fn with_file(self, path: &Path) -> Self {
+        match self {
+            SolveError::Parse(e) => SolveError::Parse(e.with_file(path)),
+            SolveError::NoAnswer { part, reason, .. } => SolveError::NoAnswer {
+                file: Some(path.to_path_buf()),
+                part,
+                reason,
+            },
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::NoAnswer { file, part, reason } => {
+                if let Some(file) = file {
+                    write!(f, "{}: ", file.display())?;
+                }
+                write!(f, "part {}: {}", part.number(), reason)
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+pub fn solve<S: Solution>(
+    input: &str,
+    kind: InputKind,
+    part: Option<Part>,
+) -> Result<Outcome, SolveError> {
+    let mut outcome = Outcome::default();
+
+    let t0 = Instant::now();
+    let parsed = S::parse_kind(input, kind)?;
+    outcome.timings.parse = t0.elapsed();
+
+    for p in Part::selected(part) {
+        let t0 = Instant::now();
+        let answer = match p {
+            Part::One => S::part1(&parsed).map(|a| a.to_string()),
+            Part::Two => S::part2(&parsed).map(|a| a.to_string()),
+        };
+        let answer = answer.map_err(|reason| SolveError::NoAnswer {
+            file: None,
+            part: p,
+            reason,
+        })?;
+        outcome.timings.set(p, Some(t0.elapsed()));
+        outcome.answers.set(p, Some(answer));
+    }
+    Ok(outcome)
+}